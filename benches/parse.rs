@@ -0,0 +1,83 @@
+//! Parses `demo.json` scaled up by repeating its pages, with every parser.
+//!
+//! Run with `cargo bench --bench parse`; `BENCH_SCALE` sets the number of
+//! copies of the pages (default 25).
+
+use std::{
+    io::Cursor,
+    time::{Duration, Instant},
+};
+
+use figma2sprout::{
+    gen::node_util::convert_json_to_figma,
+    schema::parser::{parse_outline, parse_reader, LazyFile},
+};
+use serde_json::Value;
+
+const RUNS: u32 = 5;
+
+fn scaled_demo(scale: usize) -> String {
+    let json = std::fs::read_to_string("demo.json").expect("demo.json");
+    let mut file: Value = serde_json::from_str(&json).unwrap();
+    let pages = file["document"]["children"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let mut scaled = vec![];
+    for copy in 0..scale {
+        for page in &pages {
+            let mut page = page.clone();
+            page["id"] = Value::String(format!("{}-{}", page["id"].as_str().unwrap_or(""), copy));
+            scaled.push(page);
+        }
+    }
+    file["document"]["children"] = Value::Array(scaled);
+    serde_json::to_string(&file).unwrap()
+}
+
+fn bench(name: &str, size: usize, mut run: impl FnMut()) {
+    let mut best = Duration::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        run();
+        best = best.min(start.elapsed());
+    }
+    let throughput = size as f64 / best.as_secs_f64() / 1e6;
+    println!("{:<28} {:>10.1?} {:>8.1} MB/s", name, best, throughput);
+}
+
+fn main() {
+    let scale = std::env::var("BENCH_SCALE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(25);
+    let json = scaled_demo(scale);
+    println!("{} copies, {:.1} MB, best of {}", scale, json.len() as f64 / 1e6, RUNS);
+
+    bench("convert_json_to_figma", json.len(), || {
+        convert_json_to_figma(json.clone()).unwrap();
+    });
+    bench("parse_reader", json.len(), || {
+        parse_reader(Cursor::new(json.as_bytes())).unwrap();
+    });
+    bench("parse_outline (borrowed)", json.len(), || {
+        parse_outline(&json).unwrap();
+    });
+    bench("LazyFile, no page open", json.len(), || {
+        LazyFile::from_reader(Cursor::new(json.as_bytes()))
+            .unwrap()
+            .to_file(&[])
+            .unwrap();
+    });
+    bench("LazyFile::from_slice", json.len(), || {
+        LazyFile::from_slice(json.as_bytes())
+            .unwrap()
+            .to_file(&[])
+            .unwrap();
+    });
+    bench("LazyFile, first page open", json.len(), || {
+        let file = LazyFile::from_reader(Cursor::new(json.as_bytes())).unwrap();
+        let first = file.pages()[0].id.clone();
+        file.to_file(&[&first]).unwrap();
+    });
+}
@@ -0,0 +1,549 @@
+use crate::{
+    cache::{self, CacheStatus},
+    figma_api::FigmaApi,
+    files::variables_path,
+    gen::{
+        component_generator::Generators,
+        context::GenContext,
+        coverage::{coverage_report, CoverageReport},
+        node_util::NodeIndex,
+        token_export::{
+            collect_style_tokens, collect_variable_tokens, to_css_variables, to_design_tokens_json,
+        },
+        tokens::TokenResolver,
+        validation::{validate_node_types, PropertyDefinitions},
+        variables::{convert_json_to_local_variables, VariableResolver},
+    },
+    schema::{
+        parser::{LazyFile, LazyPage},
+        File as FigmaFile, NodePath,
+    },
+    settings::{env_token, load_token, save_token, Settings},
+    ui::tree::{parse_file_to_tree, NodeMessage, TreeNode},
+};
+use iced::widget::{column, container, pick_list, row, text, text_input, Button};
+use iced::{window, Alignment, Element, Font, Length, Pixels, Size, Subscription, Task};
+use iced_widget::{button, pane_grid, scrollable, PaneGrid};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The file parsed when there is no recent file.
+const DEFAULT_FILE: &str = "demo.json";
+const TOKENS_JSON_FILE: &str = "tokens.json";
+const TOKENS_CSS_FILE: &str = "tokens.css";
+const COVERAGE_FILE: &str = "coverage.json";
+
+fn save_to_file(data: &str, file_path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+    let mut file = File::create(file_path)?;
+
+    file.write_all(data.as_bytes())?;
+    Ok(())
+}
+fn read_json_file(file_path: impl AsRef<Path>) -> Result<String, Box<dyn std::error::Error>> {
+    let file = File::open(file_path)?;
+    let mut reader = std::io::BufReader::new(file);
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+/// The definitions of the components on every page of `file`, loading one
+/// page at a time.
+fn page_definitions(file: &LazyFile) -> Result<PropertyDefinitions, String> {
+    let mut definitions = PropertyDefinitions::new();
+    for page in file.pages() {
+        definitions.add_file(&file.to_file(&[&page.id])?);
+    }
+    Ok(definitions)
+}
+
+/// The panes of the main window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pane {
+    Tree,
+    Coverage,
+}
+/// The node tree above the coverage report, split at `tree_ratio`.
+fn panes(tree_ratio: f32) -> pane_grid::State<Pane> {
+    pane_grid::State::with_configuration(pane_grid::Configuration::Split {
+        axis: pane_grid::Axis::Horizontal,
+        ratio: tree_ratio.clamp(0.1, 0.9),
+        a: Box::new(pane_grid::Configuration::Pane(Pane::Tree)),
+        b: Box::new(pane_grid::Configuration::Pane(Pane::Coverage)),
+    })
+}
+
+/// A page of the parsed file in the page picker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageChoice {
+    pub id: String,
+    pub name: String,
+}
+impl From<&LazyPage> for PageChoice {
+    fn from(page: &LazyPage) -> Self {
+        PageChoice { id: page.id.clone(), name: page.name.clone() }
+    }
+}
+impl fmt::Display for PageChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    TokenChanged(String),
+    FileIDChanged(String),
+    FilePathChanged(String),
+    RecentFileSelected(String),
+    TreeNode(NodePath, NodeMessage),
+    ParseJson,
+    PageSelected(PageChoice),
+    FetchJson,
+    ExportTokens,
+    ToggleCoverage,
+    ExportCoverage,
+    JsonFetched(Result<String, String>),
+    JsonIsParsed(Result<Vec<TreeNode>, String>),
+    WindowResized(Size),
+    PaneResized(pane_grid::ResizeEvent),
+    CloseRequested(window::Id),
+}
+
+pub struct FigmaClient {
+    pub token: String,
+    pub file_id: String,
+    /// The path of the Figma JSON file to parse.
+    pub file_path: String,
+    settings: Settings,
+    pub root_node: Option<Vec<TreeNode>>,
+    /// The parsed file, whose pages are only loaded once they are opened.
+    lazy_file: Option<LazyFile>,
+    /// The open page of `lazy_file`.
+    page: Option<PageChoice>,
+    /// `lazy_file` with the open page loaded.
+    figma_file: Option<Arc<FigmaFile>>,
+    index: Option<NodeIndex>,
+    definitions: PropertyDefinitions,
+    tokens: TokenResolver,
+    variables: VariableResolver,
+    fetching: bool,
+    /// The coverage report of the file, while its panel is shown.
+    coverage: Option<CoverageReport>,
+    /// The outcome of the last token export, shown below the file row.
+    token_export: Option<Result<String, String>>,
+    panes: pane_grid::State<Pane>,
+    /// The API client shared by all fetches, none if it could not be set up.
+    api: Option<FigmaApi>,
+    generators: Generators,
+}
+
+impl FigmaClient {
+    pub fn new() -> Self {
+        let mut generators = Generators::new();
+        generators.register_generators();
+        let settings = Settings::load();
+        FigmaClient {
+            token: load_token().unwrap_or_default(),
+            file_id: settings.file_id.clone(),
+            file_path: settings
+                .recent_files
+                .most_recent()
+                .unwrap_or(DEFAULT_FILE)
+                .to_string(),
+            panes: panes(settings.window.tree_ratio),
+            settings,
+            root_node: Some(vec![]),
+            lazy_file: None,
+            page: None,
+            figma_file: None,
+            index: None,
+            definitions: PropertyDefinitions::new(),
+            tokens: TokenResolver::new(),
+            variables: VariableResolver::default(),
+            fetching: false,
+            coverage: None,
+            token_export: None,
+            api: FigmaApi::new("")
+                .inspect_err(|e| println!("Could not set up the API client: {}", e))
+                .ok(),
+            generators,
+        }
+    }
+    /// The client parsing `file_path` on start, e.g. from the `--file` argument.
+    pub fn with_file(file_path: Option<String>) -> (Self, Task<Message>) {
+        let mut client = FigmaClient::new();
+        match file_path {
+            Some(file_path) => {
+                client.file_path = file_path;
+                (client, Task::done(Message::ParseJson))
+            },
+            None => (client, Task::none()),
+        }
+    }
+    pub fn subscription(&self) -> Subscription<Message> {
+        Subscription::batch([
+            window::resize_events().map(|(_, size)| Message::WindowResized(size)),
+            window::close_requests().map(Message::CloseRequested),
+        ])
+    }
+    /// Exports the style and variable tokens of every page of `lazy_file`,
+    /// and describes what was exported.
+    fn export_tokens(&self, lazy_file: &LazyFile) -> Result<String, String> {
+        // styles only have a value on the nodes using them, on any page
+        let pages: Vec<&str> = lazy_file
+            .pages()
+            .iter()
+            .map(|page| page.id.as_str())
+            .collect();
+        let (mut tokens, skipped) = collect_style_tokens(&lazy_file.to_file(&pages)?);
+        for token in collect_variable_tokens(&self.variables) {
+            if !tokens.iter().any(|t| t.name == token.name) {
+                tokens.push(token);
+            }
+        }
+        let dir = Path::new(&self.settings.generator.export_dir);
+        save_to_file(&to_design_tokens_json(&tokens), dir.join(TOKENS_JSON_FILE))
+            .and_then(|_| save_to_file(&to_css_variables(&tokens), dir.join(TOKENS_CSS_FILE)))
+            .map_err(|e| format!("Could not export the tokens: {}", e))?;
+        let mut report = format!("{} tokens exported to {}", tokens.len(), dir.display());
+        if !skipped.is_empty() {
+            report.push_str(&format!("\nStyles without value: {}", skipped.join(", ")));
+        }
+        Ok(report)
+    }
+    fn save_settings(&self) {
+        if let Err(e) = self.settings.save() {
+            println!("Could not save the settings: {}", e);
+        }
+    }
+    pub fn update(&mut self, event: Message) -> Task<Message> {
+        match event {
+            Message::TokenChanged(token) => {
+                self.token = token;
+                Task::none()
+            },
+            Message::FileIDChanged(file_id) => {
+                self.file_id = file_id;
+                Task::none()
+            },
+            Message::FilePathChanged(file_path) => {
+                self.file_path = file_path;
+                Task::none()
+            },
+            Message::RecentFileSelected(file_path) => {
+                self.file_path = file_path;
+                Task::done(Message::ParseJson)
+            },
+            Message::TreeNode(path, msg) => {
+                let Some(found_child) = self
+                    .root_node
+                    .iter_mut()
+                    .flatten()
+                    .find_map(|node| node.find_child(&path))
+                else {
+                    return Task::none();
+                };
+                found_child.update(msg);
+                Task::none()
+            },
+            Message::FetchJson => {
+                if let Some(api) = self.api.as_ref().filter(|_| !self.fetching) {
+                    self.fetching = true;
+                    self.settings.file_id = self.file_id.clone();
+                    self.save_settings();
+                    let api = api.with_token(&self.token);
+                    let file_id = self.file_id.clone();
+                    Task::perform(
+                        async move {
+                            match cache::fetch_file(&api, &file_id).await {
+                                Ok((path, status)) => {
+                                    if status == CacheStatus::Fresh {
+                                        println!("{} is up to date", file_id);
+                                    }
+                                    Ok(path.display().to_string())
+                                },
+                                Err(e) => Err(e.to_string()),
+                            }
+                        },
+                        Message::JsonFetched,
+                    )
+                } else {
+                    Task::none()
+                }
+            },
+            Message::ParseJson => {
+                match LazyFile::read(&self.file_path) {
+                    Ok(lazy_file) => {
+                        self.settings.recent_files.push(&self.file_path);
+                        self.save_settings();
+                        self.definitions = page_definitions(&lazy_file)
+                            .inspect_err(|e| println!("{}", e))
+                            .unwrap_or_default();
+                        let variables_file = variables_path(Path::new(&self.file_path));
+                        self.variables = VariableResolver::default();
+                        if let Some(Ok(json)) = variables_file.map(read_json_file) {
+                            match convert_json_to_local_variables(&json) {
+                                Ok(variables) => self.variables = VariableResolver::new(variables),
+                                Err(e) => println!("{}", e),
+                            }
+                        }
+                        let page = lazy_file.pages().first().map(PageChoice::from);
+                        self.lazy_file = Some(lazy_file);
+                        self.token_export = None;
+                        if let Some(page) = page {
+                            return Task::done(Message::PageSelected(page));
+                        }
+                    },
+                    Err(e) => {
+                        println!("{}", e);
+                    },
+                }
+                Task::none()
+            },
+            Message::PageSelected(page) => {
+                let Some(lazy_file) = &self.lazy_file else {
+                    return Task::none();
+                };
+                match lazy_file.to_file(&[&page.id]) {
+                    Ok(figma_file) => {
+                        for diagnostic in validate_node_types(&figma_file) {
+                            println!("{}", diagnostic);
+                        }
+                        self.tokens = TokenResolver::from_file(&figma_file);
+                        self.tokens.add_local_variables(&self.variables);
+                        let figma_file = Arc::new(figma_file);
+                        self.page = Some(page);
+                        self.figma_file = Some(figma_file.clone());
+                        self.index = Some(NodeIndex::new(figma_file.clone()));
+                        self.coverage = None;
+                        Task::perform(parse_file_to_tree(figma_file), Message::JsonIsParsed)
+                    },
+                    Err(e) => {
+                        println!("{}", e);
+                        Task::none()
+                    },
+                }
+            },
+            Message::ExportTokens => {
+                if let Some(lazy_file) = &self.lazy_file {
+                    self.token_export = Some(self.export_tokens(lazy_file));
+                }
+                Task::none()
+            },
+            Message::ToggleCoverage => {
+                self.coverage = match (&self.coverage, &self.figma_file) {
+                    (None, Some(figma_file)) => {
+                        let ctx = GenContext::new(
+                            figma_file,
+                            &self.generators,
+                            &self.definitions,
+                            &self.tokens,
+                        );
+                        Some(coverage_report(&ctx))
+                    },
+                    _ => None,
+                };
+                Task::none()
+            },
+            Message::ExportCoverage => {
+                if let Some(report) = &self.coverage {
+                    let path = Path::new(&self.settings.generator.export_dir).join(COVERAGE_FILE);
+                    match save_to_file(&report.to_json(), &path) {
+                        Ok(()) => println!("Coverage exported to {}", path.display()),
+                        Err(e) => println!("Error: {}", e),
+                    }
+                }
+                Task::none()
+            },
+            Message::JsonIsParsed(result) => {
+                if let Ok(mut nodes) = result {
+                    if let (Some(index), Some(figma_file)) = (&self.index, &self.figma_file) {
+                        let ctx = GenContext::new(
+                            figma_file,
+                            &self.generators,
+                            &self.definitions,
+                            &self.tokens,
+                        );
+                        for node in &mut nodes {
+                            node.update_status(index, &self.generators);
+                            if self.settings.generator.diagnostics {
+                                node.update_diagnostics(index, &ctx);
+                            }
+                        }
+                    }
+                    self.root_node = Some(nodes);
+                }
+                Task::none()
+            },
+            Message::JsonFetched(result) => {
+                self.fetching = false;
+                match result {
+                    Ok(file_path) => {
+                        println!("json fetched: {}", file_path);
+                        // a token from the environment is not ours to keep
+                        if env_token().is_none() {
+                            if let Err(e) = save_token(&self.token) {
+                                println!("Could not save the token: {}", e);
+                            }
+                        }
+                        self.file_path = file_path;
+                        Task::done(Message::ParseJson)
+                    },
+                    Err(error) => {
+                        println!("Error: {}", error);
+                        Task::none()
+                    },
+                }
+            },
+            Message::WindowResized(size) => {
+                self.settings.window.width = size.width;
+                self.settings.window.height = size.height;
+                Task::none()
+            },
+            Message::PaneResized(pane_grid::ResizeEvent { split, ratio }) => {
+                self.panes.resize(split, ratio);
+                self.settings.window.tree_ratio = ratio;
+                Task::none()
+            },
+            Message::CloseRequested(id) => {
+                self.save_settings();
+                window::close(id)
+            },
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let token_input = text_input("Personal Access Token", &self.token)
+            .on_input(Message::TokenChanged)
+            .secure(true)
+            .icon(text_input::Icon {
+                font: Font::with_name("my_fonts"),
+                code_point: '\u{E800}',
+                size: Some(Pixels(20.0)),
+                spacing: 5.0,
+                side: text_input::Side::Right,
+            })
+            .padding(5)
+            .size(20);
+
+        let file_id_input = text_input("File id", &self.file_id)
+            .on_input(Message::FileIDChanged)
+            .padding(5)
+            .size(20);
+
+        let inputs_column = column![token_input, file_id_input]
+            .spacing(10)
+            .width(Length::FillPortion(3));
+
+        let fetch_button: Button<'_, Message> =
+            button(if self.fetching { "Fetching..." } else { "Fetch" })
+                .on_press(Message::FetchJson)
+                .style(if self.fetching { button::secondary } else { button::primary });
+
+        let button_column = column![fetch_button]
+            .width(Length::FillPortion(1))
+            .align_x(Alignment::Center);
+
+        let input_row = row![inputs_column, button_column]
+            .spacing(20)
+            .align_y(Alignment::Center);
+
+        let file_path_input = text_input("Figma JSON file", &self.file_path)
+            .on_input(Message::FilePathChanged)
+            .on_submit(Message::ParseJson)
+            .padding(5)
+            .size(20);
+
+        let recent_files = pick_list(
+            self.settings.recent_files.paths(),
+            None::<String>,
+            Message::RecentFileSelected,
+        )
+        .placeholder("Recent files");
+
+        let pages = pick_list(
+            self.lazy_file
+                .iter()
+                .flat_map(LazyFile::pages)
+                .map(PageChoice::from)
+                .collect::<Vec<_>>(),
+            self.page.clone(),
+            Message::PageSelected,
+        )
+        .placeholder("Page");
+
+        let parse_button = button("Parse")
+            .on_press_maybe((!self.file_path.is_empty()).then_some(Message::ParseJson))
+            .style(button::primary);
+
+        let export_button = button("Export tokens")
+            .on_press_maybe(self.lazy_file.as_ref().map(|_| Message::ExportTokens))
+            .style(button::secondary);
+
+        let coverage_button = button("Coverage")
+            .on_press_maybe(self.figma_file.as_ref().map(|_| Message::ToggleCoverage))
+            .style(if self.coverage.is_some() { button::primary } else { button::secondary });
+
+        let file_row = row![
+            file_path_input,
+            recent_files,
+            parse_button,
+            pages,
+            export_button,
+            coverage_button
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let mut main_column = column![input_row, file_row].spacing(10);
+        if let Some(export) = &self.token_export {
+            let report = match export {
+                Ok(report) => text(report.as_str()),
+                Err(error) => text(error.as_str()).style(text::danger),
+            };
+            main_column = main_column.push(report.size(14));
+        }
+
+        let tree = || {
+            let nodes = self.root_node.iter().flatten().map(TreeNode::view);
+            scrollable(column(nodes)).height(Length::Fill)
+        };
+        let main_column = match &self.coverage {
+            Some(report) => {
+                let panes = PaneGrid::new(&self.panes, |_, pane, _| {
+                    pane_grid::Content::new(match pane {
+                        Pane::Tree => Element::from(tree()),
+                        Pane::Coverage => {
+                            let export_button = button("Export JSON")
+                                .on_press(Message::ExportCoverage)
+                                .style(button::secondary);
+                            let table =
+                                scrollable(text(report.to_table()).font(Font::MONOSPACE).size(14))
+                                    .direction(scrollable::Direction::Both {
+                                        vertical: scrollable::Scrollbar::default(),
+                                        horizontal: scrollable::Scrollbar::default(),
+                                    });
+                            column![export_button, table].spacing(5).into()
+                        },
+                    })
+                })
+                .spacing(10)
+                .on_resize(10, Message::PaneResized);
+                main_column.push(panes)
+            },
+            None => main_column.push(tree()),
+        };
+
+        container(main_column).padding(10).into()
+    }
+}
+impl Default for FigmaClient {
+    fn default() -> Self {
+        FigmaClient::new()
+    }
+}
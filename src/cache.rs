@@ -0,0 +1,211 @@
+use crate::{
+    figma_api::FigmaApi,
+    files::{file_cache_dir, files_dir, is_file_key, FIGMA_FILE, VARIABLES_FILE},
+    gen::variables::convert_json_to_local_variables,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The file describing a cache entry, next to the fetched file.
+const ENTRY_FILE: &str = "entry.json";
+
+/// What identifies a revision of a Figma file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMeta {
+    pub name: String,
+    pub version: String,
+    pub last_modified: String,
+}
+impl FileMeta {
+    pub fn from_json(json: &str) -> serde_json::Result<FileMeta> {
+        let header: FileHeader = serde_json::from_str(json)?;
+        Ok(FileMeta {
+            name: header.name.into_owned(),
+            version: header.version.into_owned(),
+            last_modified: header.last_modified.into_owned(),
+        })
+    }
+}
+/// The fields of a file JSON that make up its [`FileMeta`]. The other fields,
+/// the pages among them, are only scanned past, without building any value.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileHeader<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    version: Cow<'a, str>,
+    #[serde(borrow)]
+    last_modified: Cow<'a, str>,
+}
+
+/// A Figma file fetched to the cache.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    pub key: String,
+    #[serde(flatten)]
+    pub meta: FileMeta,
+    /// When the file was fetched, in seconds since the Unix epoch.
+    pub fetched_at: u64,
+}
+impl CacheEntry {
+    pub fn dir(&self) -> PathBuf {
+        // the key of an entry was checked when it was fetched or read
+        files_dir().join(&self.key)
+    }
+    pub fn file_path(&self) -> PathBuf {
+        self.dir().join(FIGMA_FILE)
+    }
+    /// The size of the cached files in bytes.
+    pub fn size(&self) -> u64 {
+        fs::read_dir(self.dir())
+            .map(|files| {
+                files
+                    .flatten()
+                    .filter_map(|file| file.metadata().ok())
+                    .map(|metadata| metadata.len())
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+    /// The time since the file was fetched.
+    pub fn age(&self) -> Duration {
+        Duration::from_secs(now().saturating_sub(self.fetched_at))
+    }
+    /// Whether the cached file is the revision `meta` describes.
+    pub fn is_current(&self, meta: &FileMeta) -> bool {
+        self.meta.version == meta.version
+            && self.meta.last_modified == meta.last_modified
+            && self.file_path().is_file()
+    }
+}
+impl fmt::Display for CacheEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\tversion {}\tmodified {}\t{} KiB\tfetched {} ago",
+            self.key,
+            self.meta.name,
+            self.meta.version,
+            self.meta.last_modified,
+            self.size().div_ceil(1024),
+            format_age(self.age())
+        )
+    }
+}
+
+/// Where a fetched file came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// The cached file is the latest version, nothing was downloaded.
+    Fresh,
+    /// The file changed since it was cached and was downloaded again.
+    Updated,
+    /// The file was not cached yet.
+    New,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+/// The cache entry of the file `file_key`, if it was fetched.
+pub fn entry(file_key: &str) -> Option<CacheEntry> {
+    read_entry(&file_cache_dir(file_key).ok()?)
+}
+/// The entry in `dir`, if it describes the file of that directory.
+fn read_entry(dir: &Path) -> Option<CacheEntry> {
+    let json = fs::read_to_string(dir.join(ENTRY_FILE)).ok()?;
+    let entry: CacheEntry = serde_json::from_str(&json).ok()?;
+    let key = dir.file_name()?.to_str()?;
+    (is_file_key(key) && entry.key == key).then_some(entry)
+}
+async fn write_entry(entry: &CacheEntry) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(entry).map_err(io::Error::other)?;
+    tokio::fs::write(entry.dir().join(ENTRY_FILE), json).await
+}
+
+/// All cache entries, the most recently fetched first.
+pub fn entries() -> Vec<CacheEntry> {
+    let mut entries: Vec<CacheEntry> = fs::read_dir(files_dir())
+        .map(|dirs| {
+            dirs.flatten()
+                .filter_map(|dir| read_entry(&dir.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.fetched_at));
+    entries
+}
+
+/// Removes the cached file `file_key`.
+pub fn remove(file_key: &str) -> io::Result<()> {
+    fs::remove_dir_all(file_cache_dir(file_key)?)
+}
+
+/// Removes the entries fetched longer than `older_than` ago, all of them
+/// without a limit, and returns the removed entries.
+pub fn prune(older_than: Option<Duration>) -> io::Result<Vec<CacheEntry>> {
+    let mut removed = vec![];
+    for entry in entries() {
+        if older_than.is_none_or(|age| entry.age() > age) {
+            remove(&entry.key)?;
+            removed.push(entry);
+        }
+    }
+    Ok(removed)
+}
+
+/// Fetches the file `file_key` and its variables to its cache directory,
+/// unless the cached copy is still the latest version. Returns the path of
+/// the file.
+pub async fn fetch_file(
+    api: &FigmaApi,
+    file_key: &str,
+) -> Result<(PathBuf, CacheStatus), Box<dyn Error>> {
+    let dir = file_cache_dir(file_key)?;
+    let meta = FileMeta::from_json(&api.fetch_file_meta(file_key).await?)?;
+    let cached = entry(file_key);
+    if let Some(entry) = cached.as_ref().filter(|entry| entry.is_current(&meta)) {
+        return Ok((entry.file_path(), CacheStatus::Fresh));
+    }
+
+    tokio::fs::create_dir_all(&dir).await?;
+    let file_path = dir.join(FIGMA_FILE);
+    let response = api.fetch_figma_file(file_key).await?;
+    // the full file is newer than the metadata if it changed in between
+    let meta = FileMeta::from_json(&response).unwrap_or(meta);
+    tokio::fs::write(&file_path, response).await?;
+    // The variables API is not available on every plan, the file is usable without it.
+    match api.fetch_local_variables(file_key).await {
+        Ok(variables) if convert_json_to_local_variables(&variables).is_ok() => {
+            tokio::fs::write(dir.join(VARIABLES_FILE), variables).await?
+        },
+        _ => {
+            let _ = tokio::fs::remove_file(dir.join(VARIABLES_FILE)).await;
+        },
+    }
+    write_entry(&CacheEntry { key: file_key.to_string(), meta, fetched_at: now() }).await?;
+    let status = if cached.is_some() { CacheStatus::Updated } else { CacheStatus::New };
+    Ok((file_path, status))
+}
@@ -1,16 +1,258 @@
-use reqwest::Client;
-
-pub async fn fetch_figma_file(
-    file_key: &str,
-    access_token: &str,
-) -> Result<String, Box<dyn std::error::Error>> {
-    let client = Client::new();
-    let url = format!("https://api.figma.com/v1/files/{}", file_key);
-    let response = client
-        .get(&url)
-        .header("X-Figma-Token", access_token)
-        .send()
-        .await?;
-    let body = response.text().await?;
-    Ok(body)
+use futures::future::try_join_all;
+use reqwest::{header::RETRY_AFTER, Client, Response, StatusCode};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
+use tokio::sync::Semaphore;
+
+const API_ROOT: &str = "https://api.figma.com/v1";
+/// Overrides the root of the API, e.g. for a local mock server.
+pub const API_ROOT_ENV: &str = "FIGMA_API_ROOT";
+/// The most node ids rendered by one images request.
+const IMAGE_BATCH_SIZE: usize = 50;
+
+/// The root of the API requests are sent to.
+pub fn api_root() -> String {
+    std::env::var(API_ROOT_ENV)
+        .ok()
+        .filter(|root| !root.is_empty())
+        .map(|root| root.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| API_ROOT.to_string())
+}
+
+/// A failed request.
+#[derive(Debug)]
+pub enum ApiError {
+    Http(reqwest::Error),
+    /// The API answered with an error, its body usually says why.
+    Status {
+        status: StatusCode,
+        body: String,
+    },
+    Json(serde_json::Error),
+    /// The API could not render the images of a request, its `err` says why.
+    Images(String),
+}
+impl ApiError {
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ApiError::Http(e) => e.status(),
+            ApiError::Status { status, .. } => Some(*status),
+            ApiError::Json(_) | ApiError::Images(_) => None,
+        }
+    }
+}
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Http(e) => write!(f, "{}", e),
+            ApiError::Status { status, body } => write!(f, "{}: {}", status, body),
+            ApiError::Json(e) => write!(f, "invalid response: {}", e),
+            ApiError::Images(err) => write!(f, "could not render the images: {}", err),
+        }
+    }
+}
+impl std::error::Error for ApiError {}
+impl From<reqwest::Error> for ApiError {
+    fn from(e: reqwest::Error) -> Self {
+        ApiError::Http(e)
+    }
+}
+impl From<serde_json::Error> for ApiError {
+    fn from(e: serde_json::Error) -> Self {
+        ApiError::Json(e)
+    }
+}
+
+/// How the API client sends requests.
+#[derive(Debug, Clone)]
+pub struct ApiConfig {
+    /// The root of the API, `FIGMA_API_ROOT` or the Figma API by default.
+    pub root: String,
+    pub user_agent: String,
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    /// How often a rate limited, failed or timed out request is sent again.
+    pub max_retries: u32,
+    /// The wait before the first retry, doubled for every further one.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// The most requests in flight at once.
+    pub max_concurrent_requests: usize,
+}
+impl Default for ApiConfig {
+    fn default() -> Self {
+        ApiConfig {
+            root: api_root(),
+            user_agent: format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            timeout: Duration::from_secs(120),
+            connect_timeout: Duration::from_secs(10),
+            max_retries: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_concurrent_requests: 4,
+        }
+    }
+}
+impl ApiConfig {
+    /// The wait before retry number `attempt`, starting at 0.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Deserialize)]
+struct ImagesResponse {
+    err: Option<String>,
+    #[serde(default)]
+    images: HashMap<String, Option<String>>,
+}
+
+/// A client of the Figma REST API, cheap to clone and share between requests.
+#[derive(Debug, Clone)]
+pub struct FigmaApi {
+    http: Client,
+    config: Arc<ApiConfig>,
+    access_token: String,
+    limiter: Arc<Semaphore>,
+}
+
+impl FigmaApi {
+    pub fn new(access_token: &str) -> Result<Self, ApiError> {
+        FigmaApi::with_config(access_token, ApiConfig::default())
+    }
+    pub fn with_config(access_token: &str, config: ApiConfig) -> Result<Self, ApiError> {
+        let http = Client::builder()
+            .user_agent(&config.user_agent)
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .gzip(true)
+            .build()?;
+        Ok(FigmaApi {
+            http,
+            limiter: Arc::new(Semaphore::new(config.max_concurrent_requests.max(1))),
+            config: Arc::new(config),
+            access_token: access_token.to_string(),
+        })
+    }
+    /// The client sending requests with `access_token` instead.
+    pub fn with_token(&self, access_token: &str) -> Self {
+        FigmaApi { access_token: access_token.to_string(), ..self.clone() }
+    }
+    pub fn config(&self) -> &ApiConfig {
+        &self.config
+    }
+
+    /// Sends a GET request to `path` below the API root with the encoded
+    /// `query`, and retries it when it was rate limited, failed on the server
+    /// or timed out.
+    async fn get(&self, path: &str, query: &[(&str, &str)]) -> Result<String, ApiError> {
+        let url = format!("{}{}", self.config.root, path);
+        let mut attempt = 0;
+        loop {
+            let result = {
+                let _permit = self
+                    .limiter
+                    .acquire()
+                    .await
+                    .expect("the limiter is never closed");
+                self.http
+                    .get(&url)
+                    .query(query)
+                    .header("X-Figma-Token", &self.access_token)
+                    .send()
+                    .await
+            };
+            let retry = attempt < self.config.max_retries;
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    return Ok(response.text().await?);
+                },
+                Ok(response) if retry && is_retryable(response.status()) => {
+                    // the wait the server asks for is capped at max_backoff
+                    let wait = retry_after(&response)
+                        .map(|wait| wait.min(self.config.max_backoff))
+                        .unwrap_or(self.config.backoff(attempt));
+                    tokio::time::sleep(wait).await;
+                },
+                Ok(response) => {
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(ApiError::Status { status, body });
+                },
+                Err(e) if retry && (e.is_timeout() || e.is_connect()) => {
+                    tokio::time::sleep(self.config.backoff(attempt)).await;
+                },
+                Err(e) => return Err(e.into()),
+            }
+            attempt += 1;
+        }
+    }
+
+    pub async fn fetch_figma_file(&self, file_key: &str) -> Result<String, ApiError> {
+        self.get(&format!("/files/{}", file_key), &[]).await
+    }
+
+    /// Fetches the file without its pages: enough for its name, version and
+    /// last modification.
+    pub async fn fetch_file_meta(&self, file_key: &str) -> Result<String, ApiError> {
+        self.get(&format!("/files/{}", file_key), &[("depth", "1")])
+            .await
+    }
+
+    /// Fetches the nodes `ids` of a file with the components and styles they
+    /// use.
+    pub async fn fetch_nodes(&self, file_key: &str, ids: &[&str]) -> Result<String, ApiError> {
+        self.get(&format!("/files/{}/nodes", file_key), &[("ids", &ids.join(","))])
+            .await
+    }
+
+    /// Fetches the local variables and variable collections of a file,
+    /// including the values of each mode.
+    pub async fn fetch_local_variables(&self, file_key: &str) -> Result<String, ApiError> {
+        self.get(&format!("/files/{}/variables/local", file_key), &[])
+            .await
+    }
+
+    /// Fetches the variables a library file publishes. Published variables
+    /// carry names and keys but no values.
+    pub async fn fetch_published_variables(&self, file_key: &str) -> Result<String, ApiError> {
+        self.get(&format!("/files/{}/variables/published", file_key), &[])
+            .await
+    }
+
+    /// Renders the nodes `ids` as images in `format` ("png", "jpg", "svg" or
+    /// "pdf"), and returns the url of each image, none if it failed to
+    /// render. The ids are sent in batches, the concurrent ones limited by
+    /// the config.
+    pub async fn fetch_images(
+        &self,
+        file_key: &str,
+        ids: &[&str],
+        format: &str,
+        scale: f32,
+    ) -> Result<HashMap<String, Option<String>>, ApiError> {
+        let batches = ids.chunks(IMAGE_BATCH_SIZE).map(|batch| async move {
+            let path = format!("/images/{}", file_key);
+            let (ids, scale) = (batch.join(","), scale.to_string());
+            let query = [("ids", ids.as_str()), ("format", format), ("scale", scale.as_str())];
+            let response: ImagesResponse = serde_json::from_str(&self.get(&path, &query).await?)?;
+            match response.err {
+                Some(err) => Err(ApiError::Images(err)),
+                None => Ok(response.images),
+            }
+        });
+        let images = try_join_all(batches).await?;
+        Ok(images.into_iter().flatten().collect())
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+/// The wait the server asks for, in seconds. Dates are not supported.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse().ok().map(Duration::from_secs)
 }
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+
+const APP_DIR: &str = "figma2sprout";
+const MAX_RECENT_FILES: usize = 10;
+
+/// The file a fetched Figma file is saved to in its cache directory.
+pub const FIGMA_FILE: &str = "file.json";
+/// The file the local variables of a Figma file are saved to, next to it.
+pub const VARIABLES_FILE: &str = "variables.json";
+
+/// The cache directory of the app: `$XDG_CACHE_HOME/figma2sprout`, or
+/// `~/.cache/figma2sprout`.
+pub fn cache_dir() -> PathBuf {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(env::temp_dir);
+    base.join(APP_DIR)
+}
+/// The config directory of the app: `$XDG_CONFIG_HOME/figma2sprout`, or
+/// `~/.config/figma2sprout`.
+pub fn config_dir() -> PathBuf {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(env::temp_dir);
+    base.join(APP_DIR)
+}
+/// Whether `file_key` looks like the key of a Figma file, which is
+/// alphanumeric and safe to use as a directory name.
+pub fn is_file_key(file_key: &str) -> bool {
+    !file_key.is_empty()
+        && file_key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+/// The directory the cached files are fetched to.
+pub fn files_dir() -> PathBuf {
+    cache_dir().join("files")
+}
+/// The directory the file `file_key` and its variables are fetched to. Keys
+/// that are empty or look like paths are rejected, so that the directory
+/// is always a single entry of the cache.
+pub fn file_cache_dir(file_key: &str) -> io::Result<PathBuf> {
+    if !is_file_key(file_key) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid file key `{}`", file_key),
+        ));
+    }
+    Ok(files_dir().join(file_key))
+}
+/// The variables file fetched with the Figma file at `path`, only for files
+/// in the cache: the variables of other files are not known.
+pub fn variables_path(path: &Path) -> Option<PathBuf> {
+    let dir = path.parent()?;
+    let in_cache = path.file_name()? == FIGMA_FILE
+        && dir.parent()? == files_dir()
+        && dir.file_name()?.to_str().is_some_and(is_file_key);
+    in_cache.then(|| dir.join(VARIABLES_FILE))
+}
+/// The value of the `--file` argument in the command line `args`, which
+/// start with the program name.
+pub fn file_arg(args: impl Iterator<Item = String>) -> Result<Option<String>, String> {
+    let mut file = None;
+    let mut args = args.skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--file" => file = Some(args.next().ok_or("--file needs a path")?),
+            _ => match arg.strip_prefix("--file=") {
+                Some(path) => file = Some(path.to_string()),
+                None => return Err(format!("unknown argument `{}`", arg)),
+            },
+        }
+    }
+    Ok(file)
+}
+
+/// The Figma files opened last, the most recent first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecentFiles {
+    paths: Vec<String>,
+}
+impl RecentFiles {
+    pub fn new() -> Self {
+        RecentFiles { paths: vec![] }
+    }
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+    pub fn most_recent(&self) -> Option<&str> {
+        self.paths.first().map(String::as_str)
+    }
+    /// Moves `path` to the front, dropping the oldest files over the limit.
+    pub fn push(&mut self, path: &str) {
+        self.paths.retain(|recent| recent != path);
+        self.paths.insert(0, path.to_string());
+        self.paths.truncate(MAX_RECENT_FILES);
+    }
+}
@@ -1,11 +1,24 @@
 use crate::{
-    gen::components::{alert::AlertGenerator, checkbox::CheckboxGenerator},
+    gen::{
+        components::{
+            alert::AlertGenerator, avatar::AvatarGenerator, badge::BadgeGenerator,
+            button::ButtonGenerator, checkbox::CheckboxGenerator, container::ContainerGenerator,
+            icon_button::IconButtonGenerator, input_button::InputButtonGenerator,
+            link::LinkGenerator, modal::ModalGenerator, tag::TagGenerator,
+            tag_group::TagGroupGenerator, typography::TypographyGenerator,
+        },
+        context::GenContext,
+        customization::customization_report,
+        jsx::ToJsx,
+        node_util::{is_shown, property_scope},
+        validation::validate_instance,
+    },
     schema::{Node, NodeType},
 };
-use std::{any::Any, rc::Rc};
+use std::any::Any;
 
 const DOC_ROOT_LINK: &str = "https://rd-sprout.qliktech.com/sprout/?path=/docs";
-pub trait Component: Any {
+pub trait Component: Any + ToJsx {
     fn name(&self) -> String;
 
     fn link(&self) -> String;
@@ -40,12 +53,25 @@ macro_rules! impl_component {
 }
 
 pub trait ComponentGenerator {
+    /// The name of the component the generator emits, e.g. `Button`.
+    fn component_name(&self) -> &'static str;
+    /// Whether the generator maps a component of the library, rather than
+    /// any frame or text.
+    fn is_library_component(&self) -> bool {
+        true
+    }
     fn is_instance_type(&self, node: &Node) -> bool {
         node.r#type == NodeType::Instance
     }
     fn can_gen_component(&self, node: &Node) -> bool;
 
-    fn gen_component(&self, node: &Node) -> Box<dyn Component>;
+    /// The component properties the generator maps, by name without their
+    /// `#id` suffix.
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component>;
 }
 
 pub struct Generators {
@@ -56,36 +82,78 @@ impl Generators {
         Generators { generators: Vec::new() }
     }
     pub fn register_generators(&mut self) {
+        self.generators.push(Box::new(ModalGenerator::new()));
         self.generators.push(Box::new(AlertGenerator::new()));
         self.generators.push(Box::new(CheckboxGenerator::new()));
+        self.generators.push(Box::new(ButtonGenerator::new()));
+        self.generators.push(Box::new(IconButtonGenerator::new()));
+        self.generators.push(Box::new(BadgeGenerator::new()));
+        self.generators.push(Box::new(TagGenerator::new()));
+        self.generators.push(Box::new(TagGroupGenerator::new()));
+        self.generators.push(Box::new(AvatarGenerator::new()));
+        self.generators.push(Box::new(LinkGenerator::new()));
+        self.generators.push(Box::new(InputButtonGenerator::new()));
+        self.generators.push(Box::new(TypographyGenerator::new()));
+        // Custom frames last, so that library components are matched first.
+        self.generators.push(Box::new(ContainerGenerator::new()));
     }
 
-    pub fn gen_component(&self, node: &Node) -> Option<Box<dyn Component>> {
-        for gen in &self.generators {
-            if gen.can_gen_component(node) {
-                let component = gen.gen_component(node);
-                return Some(component);
+    /// The generator matching `node`, if any.
+    pub fn generator_for(&self, node: &Node) -> Option<&dyn ComponentGenerator> {
+        self.generators
+            .iter()
+            .find(|gen| gen.can_gen_component(node))
+            .map(|gen| gen.as_ref())
+    }
+
+    /// Generates the component for `node`. Instances of library components
+    /// also report their mismatches with the library definitions and their
+    /// customizations to `ctx`.
+    pub fn gen_component(&self, node: &Node, ctx: &GenContext) -> Option<Box<dyn Component>> {
+        let gen = self.generator_for(node)?;
+        if node.r#type == NodeType::Instance && gen.is_library_component() {
+            for diagnostic in validate_instance(ctx.definitions, ctx.file, node) {
+                ctx.report(diagnostic);
+            }
+            for diagnostic in customization_report(node) {
+                ctx.report(diagnostic);
             }
         }
-        None
+        Some(gen.gen_component(node, ctx))
     }
-}
 
-#[macro_export]
-macro_rules! get_value_from_properties {
-    ($node:expr, $prefix:expr) => {{
-        if let Some(properties) = &$node.component_properties {
-            let value = properties
-                .iter()
-                .find(|p| p.0.starts_with($prefix))
-                .map(|p| p.1.clone());
-            if let Some(component_prop) = value {
-                component_prop.into()
-            } else {
-                None
+    /// Generates the shown descendants of `node`: every shown child is
+    /// generated, or searched in turn when no generator matches it.
+    pub fn gen_descendants(&self, node: &Node, ctx: &GenContext) -> Vec<Box<dyn Component>> {
+        let mut components = vec![];
+        self.gen_shown_descendants(node, node, ctx, &mut components);
+        components
+    }
+    fn gen_shown_descendants(
+        &self,
+        instance: &Node,
+        node: &Node,
+        ctx: &GenContext,
+        components: &mut Vec<Box<dyn Component>>,
+    ) {
+        for child in node
+            .children
+            .iter()
+            .flatten()
+            .filter(|child| is_shown(instance, child))
+        {
+            match self.gen_component(child, ctx) {
+                Some(component) => components.push(component),
+                None => {
+                    let instance = property_scope(instance, child);
+                    self.gen_shown_descendants(instance, child, ctx, components)
+                },
             }
-        } else {
-            None
         }
-    }};
+    }
+}
+impl Default for Generators {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -1,25 +1,58 @@
-use std::any::Any;
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, PropValue, ToJsx},
+    },
+    impl_component,
+};
 
-use crate::{gen::component_generator::Component, impl_component};
-
-#[derive(Default, Debug, Clone)]
+#[derive(Default)]
 pub struct ComponentAlert {
     pub severity: Option<String>,
+    pub title: Option<String>,
     pub message: Option<String>,
     pub has_title: bool,
     pub has_actions: bool,
+    /// The generated buttons and links of the action bar.
+    pub actions: Vec<Box<dyn Component>>,
     pub dismissable: bool,
 }
 impl ComponentAlert {
     pub fn new() -> Self {
         ComponentAlert {
             severity: None,
+            title: None,
             message: None,
             has_title: false,
             has_actions: false,
+            actions: vec![],
             dismissable: false,
         }
     }
 }
+impl ToJsx for ComponentAlert {
+    fn to_jsx(&self) -> JsxElement {
+        let mut alert = JsxElement::new("Alert").opt_prop("severity", self.severity.clone());
+        if self.dismissable {
+            alert = alert.prop("onClose", PropValue::Expression("() => {}".to_string()));
+        }
+        if self.has_actions {
+            let actions: String = self
+                .actions
+                .iter()
+                .map(|action| action.to_jsx().render_inline())
+                .collect();
+            alert = alert.prop("action", PropValue::Expression(format!("<>{}</>", actions)));
+        }
+        if self.has_title {
+            alert = alert
+                .child(JsxElement::new("AlertTitle").text(self.title.clone().unwrap_or_default()));
+        }
+        if let Some(message) = &self.message {
+            alert = alert.text(message.clone());
+        }
+        alert
+    }
+}
 
 impl_component!(ComponentAlert, "Alert", "/mui-components-alerts-beta--docs");
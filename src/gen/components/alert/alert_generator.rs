@@ -1,13 +1,15 @@
-use crate::{
-    gen::{
-        component_generator::{Component, ComponentGenerator},
-        components::alert::ComponentAlert,
-    },
-    get_value_from_properties,
-    schema::{ComponentProperty, Node},
+use crate::gen::{
+    component_generator::{Component, ComponentGenerator},
+    components::alert::ComponentAlert,
+    context::GenContext,
+    diagnostic::Diagnostic,
+    node_util::{exposed_instances, is_shown, property_value},
 };
-use serde_json::Value;
+use crate::schema::Node;
 
+const SEVERITIES: [&str; 4] = ["success", "info", "warning", "error"];
+
+#[derive(Default)]
 pub struct AlertGenerator {}
 
 impl AlertGenerator {
@@ -16,18 +18,46 @@ impl AlertGenerator {
     }
 }
 impl ComponentGenerator for AlertGenerator {
+    fn component_name(&self) -> &'static str {
+        "Alert"
+    }
+
     fn can_gen_component(&self, node: &Node) -> bool {
         self.is_instance_type(node) && node.name == "Alert.Inline"
     }
 
-    fn gen_component(&self, node: &Node) -> Box<dyn Component> {
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Severity", "Title", "Title text", "Message text", "Dismissable", "has Actions"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
         let mut alert = ComponentAlert::new();
 
-        alert.message = get_value_from_properties!(node, "Message text");
-        alert.severity = get_value_from_properties!(node, "Severity");
-        alert.has_title = get_value_from_properties!(node, "Title").unwrap_or(false);
-        alert.dismissable = get_value_from_properties!(node, "Dismissable").unwrap_or(false);
-        alert.has_actions = get_value_from_properties!(node, "has Actions").unwrap_or(false);
+        alert.message = property_value(node, "Message text");
+        alert.title = property_value(node, "Title text");
+        if !ctx.is_default(node, "Severity") {
+            let severity: Option<String> = property_value(node, "Severity");
+            alert.severity = severity.map(|severity| severity.to_lowercase());
+        }
+        if let Some(severity) = &alert.severity {
+            if !SEVERITIES.contains(&severity.as_str()) {
+                ctx.report(Diagnostic::warning(node, format!("unknown severity `{}`", severity)));
+            }
+        }
+        alert.has_title = property_value(node, "Title").unwrap_or(false);
+        alert.dismissable = property_value(node, "Dismissable").unwrap_or(false);
+        alert.has_actions = property_value(node, "has Actions").unwrap_or(false);
+        if alert.has_actions {
+            // the action button and link are exposed on the alert
+            for action in exposed_instances(node)
+                .into_iter()
+                .filter(|action| is_shown(node, action))
+            {
+                if let Some(component) = ctx.generators.gen_component(action, ctx) {
+                    alert.actions.push(component);
+                }
+            }
+        }
         Box::new(alert)
     }
 }
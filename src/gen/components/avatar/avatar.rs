@@ -0,0 +1,45 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+        style::image_url,
+    },
+    impl_component,
+};
+
+/// What an avatar shows.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum AvatarContent {
+    /// The `imageRef` of the image fill.
+    Image(Option<String>),
+    Initials(String),
+    Icon(String),
+    #[default]
+    Empty,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentAvatar {
+    pub size: Option<String>,
+    pub content: AvatarContent,
+}
+impl ComponentAvatar {
+    pub fn new() -> Self {
+        ComponentAvatar { size: None, content: AvatarContent::Empty }
+    }
+}
+impl ToJsx for ComponentAvatar {
+    fn to_jsx(&self) -> JsxElement {
+        let avatar = JsxElement::new("Avatar").opt_prop("size", self.size.clone());
+        match &self.content {
+            AvatarContent::Image(image_ref) => {
+                avatar.opt_prop("src", image_ref.as_deref().map(image_url))
+            },
+            AvatarContent::Initials(initials) => avatar.text(initials.clone()),
+            AvatarContent::Icon(icon) => avatar.child(JsxElement::new(icon)),
+            AvatarContent::Empty => avatar,
+        }
+    }
+}
+
+impl_component!(ComponentAvatar, "Avatar", "/mui-components-avatar--docs");
@@ -0,0 +1,81 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::avatar::{AvatarContent, ComponentAvatar},
+        context::GenContext,
+        diagnostic::Diagnostic,
+        icons::{icon_for_instance, is_icon_instance},
+        node_util::{find_node_matching, property_value, size_prop},
+        style::image_fill_diagnostics,
+    },
+    schema::{Node, PaintType},
+};
+
+#[derive(Default)]
+pub struct AvatarGenerator {}
+
+impl AvatarGenerator {
+    pub fn new() -> Self {
+        AvatarGenerator {}
+    }
+}
+
+fn has_image_fill(node: &Node) -> bool {
+    node.fills
+        .iter()
+        .flatten()
+        .any(|fill| fill.r#type == PaintType::Image)
+}
+
+impl ComponentGenerator for AvatarGenerator {
+    fn component_name(&self) -> &'static str {
+        "Avatar"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Avatar"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Size", "Type", "Text"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut avatar = ComponentAvatar::new();
+
+        if !ctx.is_default(node, "Size") {
+            avatar.size = property_value(node, "Size").and_then(size_prop);
+        }
+        let avatar_type: Option<String> = property_value(node, "Type");
+        avatar.content = match avatar_type.as_deref() {
+            Some("Image") => {
+                let image = if has_image_fill(node) {
+                    Some(node)
+                } else {
+                    find_node_matching(node, &has_image_fill, 0, 2)
+                };
+                for diagnostic in image.into_iter().flat_map(image_fill_diagnostics) {
+                    ctx.report(diagnostic);
+                }
+                let image_ref = image
+                    .and_then(|image| image.fills.as_ref())
+                    .and_then(|fills| fills.iter().find(|fill| fill.r#type == PaintType::Image))
+                    .and_then(|fill| fill.image_ref.clone());
+                AvatarContent::Image(image_ref)
+            },
+            Some("Initials") => {
+                AvatarContent::Initials(property_value(node, "Text").unwrap_or_default())
+            },
+            Some("Icon") => find_node_matching(node, &is_icon_instance, 0, 2)
+                .and_then(|icon| icon_for_instance(ctx, icon))
+                .map(AvatarContent::Icon)
+                .unwrap_or_default(),
+            Some(other) => {
+                ctx.report(Diagnostic::warning(node, format!("unknown avatar type `{}`", other)));
+                AvatarContent::Empty
+            },
+            None => AvatarContent::Empty,
+        };
+        Box::new(avatar)
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod avatar;
+mod avatar_generator;
+
+pub use avatar::*;
+pub use avatar_generator::*;
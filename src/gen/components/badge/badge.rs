@@ -0,0 +1,32 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+    },
+    impl_component,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentBadge {
+    pub content: Option<String>,
+    pub dot: bool,
+    pub color: Option<String>,
+}
+impl ComponentBadge {
+    pub fn new() -> Self {
+        ComponentBadge { content: None, dot: false, color: None }
+    }
+}
+impl ToJsx for ComponentBadge {
+    fn to_jsx(&self) -> JsxElement {
+        let badge = JsxElement::new("Badge").opt_prop("color", self.color.clone());
+        if self.dot {
+            // a dot badge has no content
+            badge.prop("variant", "dot")
+        } else {
+            badge.opt_prop("badgeContent", self.content.clone())
+        }
+    }
+}
+
+impl_component!(ComponentBadge, "Badge", "/mui-components-badge--docs");
@@ -0,0 +1,45 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::badge::ComponentBadge,
+        context::GenContext,
+        node_util::property_value,
+    },
+    schema::Node,
+};
+
+#[derive(Default)]
+pub struct BadgeGenerator {}
+
+impl BadgeGenerator {
+    pub fn new() -> Self {
+        BadgeGenerator {}
+    }
+}
+impl ComponentGenerator for BadgeGenerator {
+    fn component_name(&self) -> &'static str {
+        "Badge"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Badge"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Type", "Text", "Color"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut badge = ComponentBadge::new();
+
+        badge.content = property_value(node, "Text");
+        badge.dot = property_value(node, "Type")
+            .map(|val: String| val == "Dot")
+            .unwrap_or(false);
+        if !ctx.is_default(node, "Color") {
+            let color: Option<String> = property_value(node, "Color");
+            badge.color = color.map(|color| color.to_lowercase());
+        }
+        Box::new(badge)
+    }
+}
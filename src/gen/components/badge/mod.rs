@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod badge;
+mod badge_generator;
+
+pub use badge::*;
+pub use badge_generator::*;
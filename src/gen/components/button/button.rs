@@ -0,0 +1,45 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+    },
+    impl_component,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentButton {
+    pub label: Option<String>,
+    pub variant: Option<String>,
+    pub size: Option<String>,
+    pub disabled: bool,
+    pub start_icon: Option<String>,
+    pub end_icon: Option<String>,
+}
+impl ComponentButton {
+    pub fn new() -> Self {
+        ComponentButton {
+            label: None,
+            variant: None,
+            size: None,
+            disabled: false,
+            start_icon: None,
+            end_icon: None,
+        }
+    }
+}
+impl ToJsx for ComponentButton {
+    fn to_jsx(&self) -> JsxElement {
+        let button = JsxElement::new("Button")
+            .opt_prop("variant", self.variant.clone())
+            .opt_prop("size", self.size.clone())
+            .flag("disabled", self.disabled)
+            .opt_prop("startIcon", self.start_icon.as_deref().map(JsxElement::new))
+            .opt_prop("endIcon", self.end_icon.as_deref().map(JsxElement::new));
+        match &self.label {
+            Some(label) => button.text(label.clone()),
+            None => button,
+        }
+    }
+}
+
+impl_component!(ComponentButton, "Button", "/mui-components-button--docs");
@@ -0,0 +1,72 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::button::ComponentButton,
+        context::GenContext,
+        icons::{icon_for_component_id, icon_for_instance, is_icon_instance},
+        node_util::{
+            find_node_from_children, find_node_matching, property_value, shown_characters,
+            variant_prop,
+        },
+    },
+    schema::{Node, NodeType},
+};
+
+#[derive(Default)]
+pub struct ButtonGenerator {}
+
+impl ButtonGenerator {
+    pub fn new() -> Self {
+        ButtonGenerator {}
+    }
+}
+impl ComponentGenerator for ButtonGenerator {
+    fn component_name(&self) -> &'static str {
+        "Button"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Button"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Variant", "Size", "State", "Text", "Has icon", "Icon", "As dropdown"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut button = ComponentButton::new();
+
+        if !ctx.is_default(node, "Variant") {
+            button.variant = property_value(node, "Variant").and_then(variant_prop);
+        }
+        if !ctx.is_default(node, "Size") {
+            button.size = property_value(node, "Size").and_then(variant_prop);
+        }
+        button.disabled = property_value(node, "State")
+            .map(|val: String| val == "Disabled")
+            .unwrap_or(false);
+        button.label = find_node_from_children(node, "Button", NodeType::Text, 0, 3)
+            .and_then(|label| shown_characters(node, label))
+            .or_else(|| property_value(node, "Text"));
+
+        let has_icon: bool = property_value(node, "Has icon").unwrap_or(false);
+        if has_icon {
+            let icon: Option<String> = property_value(node, "Icon");
+            button.start_icon = icon.and_then(|id| icon_for_component_id(ctx, &id));
+        }
+        let as_dropdown: bool = property_value(node, "As dropdown").unwrap_or(false);
+        if as_dropdown {
+            // the dropdown arrow is the icon after the label
+            let mut icons = node
+                .children
+                .iter()
+                .flatten()
+                .flat_map(|content| content.children.iter().flatten())
+                .filter_map(|child| find_node_matching(child, &is_icon_instance, 0, 1));
+            button.end_icon = icons
+                .next_back()
+                .and_then(|icon| icon_for_instance(ctx, icon));
+        }
+        Box::new(button)
+    }
+}
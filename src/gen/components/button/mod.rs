@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod button;
+mod button_generator;
+
+pub use button::*;
+pub use button_generator::*;
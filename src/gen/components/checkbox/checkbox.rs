@@ -1,22 +1,26 @@
-use std::any::Any;
-
-use crate::gen::component_generator::Component;
+use crate::gen::{
+    component_generator::Component,
+    jsx::{JsxElement, ToJsx},
+};
 use crate::impl_component;
+use std::str::FromStr;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub enum CheckedType {
     True,
     #[default]
     False,
     Indeterminate,
 }
-impl From<&str> for CheckedType {
-    fn from(s: &str) -> Self {
+impl FromStr for CheckedType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "true" => CheckedType::True,
-            "false" => CheckedType::False,
-            "indeterminate" => CheckedType::Indeterminate,
-            _ => CheckedType::False,
+            "true" => Ok(CheckedType::True),
+            "false" => Ok(CheckedType::False),
+            "indeterminate" => Ok(CheckedType::Indeterminate),
+            _ => Err(()),
         }
     }
 }
@@ -39,5 +43,19 @@ impl ComponentCheckbox {
         }
     }
 }
+impl ToJsx for ComponentCheckbox {
+    fn to_jsx(&self) -> JsxElement {
+        let checkbox = JsxElement::new("Checkbox")
+            .flag("checked", self.checked == CheckedType::True)
+            .flag("indeterminate", self.checked == CheckedType::Indeterminate);
+        match (&self.label, self.has_label) {
+            (Some(label), true) => JsxElement::new("FormControlLabel")
+                .prop("control", checkbox)
+                .prop("label", label.clone())
+                .flag("disabled", self.disabled),
+            _ => checkbox.flag("disabled", self.disabled),
+        }
+    }
+}
 
-impl_component!(ComponentCheckbox, "Checkbox", "/mui-components-checkbox--docs");
\ No newline at end of file
+impl_component!(ComponentCheckbox, "Checkbox", "/mui-components-checkbox--docs");
@@ -1,43 +1,57 @@
+use crate::gen::node_util::{find_node_from_children, property_value, shown_characters};
 use crate::{
     gen::{
         component_generator::{Component, ComponentGenerator},
-        components::checkbox::ComponentCheckbox,
+        components::checkbox::{CheckedType, ComponentCheckbox},
+        context::GenContext,
+        diagnostic::Diagnostic,
     },
-    get_value_from_properties,
     schema::{Node, NodeType},
 };
-use crate::gen::node_util::find_node_from_children;
 
+#[derive(Default)]
 pub struct CheckboxGenerator {}
 
 impl CheckboxGenerator {
     pub fn new() -> Self {
         CheckboxGenerator {}
     }
-
 }
 impl ComponentGenerator for CheckboxGenerator {
+    fn component_name(&self) -> &'static str {
+        "Checkbox"
+    }
+
     fn can_gen_component(&self, node: &Node) -> bool {
         self.is_instance_type(node) && node.name == "Checkbox"
     }
 
-    fn gen_component(&self, node: &Node) -> Box<dyn Component> {
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Label", "State", "Checked"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
         let mut checkbox = ComponentCheckbox::new();
 
-        checkbox.has_label = get_value_from_properties!(node, "Label").unwrap_or(false);
-        checkbox.disabled = get_value_from_properties!(node, "State")
+        checkbox.has_label = property_value(node, "Label").unwrap_or(false);
+        checkbox.disabled = property_value(node, "State")
             .map(|val: String| val == "Disabled")
             .unwrap_or(false);
         checkbox.dismissable = false; // cannot get it from Figma
-        checkbox.checked = get_value_from_properties!(node, "Checked")
-            .map(|val: String| val.clone())
-            .unwrap()
-            .as_str()
-            .into();
-        if let Some(label_node) = find_node_from_children(node, "Label", NodeType::Text, 0, 1) {
-            if let Some(label) = &label_node.characters {
-                checkbox.label = Some(label.clone());
-            }
+        let checked: Option<String> = property_value(node, "Checked");
+        checkbox.checked = match checked {
+            Some(checked) => checked.parse().unwrap_or_else(|_| {
+                ctx.report(Diagnostic::warning(
+                    node,
+                    format!("unknown `Checked` value `{}`", checked),
+                ));
+                CheckedType::default()
+            }),
+            None => CheckedType::default(),
+        };
+        if checkbox.has_label {
+            checkbox.label = find_node_from_children(node, "Label", NodeType::Text, 0, 1)
+                .and_then(|label| shown_characters(node, label));
         }
 
         Box::new(checkbox)
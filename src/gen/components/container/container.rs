@@ -0,0 +1,31 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+        style::Sx,
+    },
+    impl_component,
+};
+
+/// A custom frame of the design, emitted as a Sprout `Box` styled with `sx`.
+#[derive(Default)]
+pub struct ComponentContainer {
+    pub sx: Sx,
+    pub children: Vec<Box<dyn Component>>,
+}
+impl ComponentContainer {
+    pub fn new() -> Self {
+        ComponentContainer { sx: Sx::new(), children: vec![] }
+    }
+}
+impl ToJsx for ComponentContainer {
+    fn to_jsx(&self) -> JsxElement {
+        let mut container = JsxElement::new("Box").opt_prop("sx", self.sx.to_prop());
+        for child in &self.children {
+            container = container.child(child.to_jsx());
+        }
+        container
+    }
+}
+
+impl_component!(ComponentContainer, "Box", "/mui-components-box--docs");
@@ -0,0 +1,48 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::container::ComponentContainer,
+        context::GenContext,
+        style::{image_fill_diagnostics, node_sx},
+    },
+    schema::{Node, NodeType},
+};
+
+/// Generates the frames, groups and rectangles that are not library
+/// components, with the generated components they contain as children.
+/// Hidden children are not generated, and the children of layers no
+/// generator matches are searched in turn.
+#[derive(Default)]
+pub struct ContainerGenerator {}
+
+impl ContainerGenerator {
+    pub fn new() -> Self {
+        ContainerGenerator {}
+    }
+}
+impl ComponentGenerator for ContainerGenerator {
+    fn component_name(&self) -> &'static str {
+        "Box"
+    }
+
+    fn is_library_component(&self) -> bool {
+        false
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        matches!(
+            node.r#type,
+            NodeType::Frame | NodeType::Group | NodeType::Rectangle | NodeType::Section
+        )
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut container = ComponentContainer::new();
+        container.sx = node_sx(node, ctx.tokens);
+        for diagnostic in image_fill_diagnostics(node) {
+            ctx.report(diagnostic);
+        }
+        container.children = ctx.generators.gen_descendants(node, ctx);
+        Box::new(container)
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod container;
+mod container_generator;
+
+pub use container::*;
+pub use container_generator::*;
@@ -0,0 +1,35 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+    },
+    impl_component,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentIconButton {
+    pub icon: Option<String>,
+    pub variant: Option<String>,
+    pub size: Option<String>,
+    pub disabled: bool,
+}
+impl ComponentIconButton {
+    pub fn new() -> Self {
+        ComponentIconButton { icon: None, variant: None, size: None, disabled: false }
+    }
+}
+impl ToJsx for ComponentIconButton {
+    fn to_jsx(&self) -> JsxElement {
+        let button = JsxElement::new("IconButton")
+            .opt_prop("variant", self.variant.clone())
+            .opt_prop("size", self.size.clone())
+            .flag("disabled", self.disabled)
+            .opt_prop("aria-label", self.icon.clone());
+        match &self.icon {
+            Some(icon) => button.child(JsxElement::new(icon)),
+            None => button,
+        }
+    }
+}
+
+impl_component!(ComponentIconButton, "IconButton", "/mui-components-iconbutton--docs");
@@ -0,0 +1,58 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::icon_button::ComponentIconButton,
+        context::GenContext,
+        icons::{icon_for_component_id, icon_for_instance, is_icon_instance},
+        node_util::{find_node_from_children, find_node_matching, property_value, variant_prop},
+    },
+    schema::{Node, NodeType},
+};
+
+#[derive(Default)]
+pub struct IconButtonGenerator {}
+
+impl IconButtonGenerator {
+    pub fn new() -> Self {
+        IconButtonGenerator {}
+    }
+}
+impl ComponentGenerator for IconButtonGenerator {
+    fn component_name(&self) -> &'static str {
+        "IconButton"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "IconButton"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Variant", "Size", "State"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut button = ComponentIconButton::new();
+
+        if !ctx.is_default(node, "Variant") {
+            button.variant = property_value(node, "Variant").and_then(variant_prop);
+        }
+        if !ctx.is_default(node, "Size") {
+            button.size = property_value(node, "Size").and_then(variant_prop);
+        }
+        button.disabled = property_value(node, "State")
+            .map(|val: String| val == "Disabled")
+            .unwrap_or(false);
+        // The icon is swapped on the nested `_icon` instance.
+        button.icon =
+            find_node_from_children(node, "_icon", NodeType::Instance, 0, 2).and_then(|icon| {
+                let swapped: Option<String> = property_value(icon, "Icon");
+                swapped
+                    .and_then(|id| icon_for_component_id(ctx, &id))
+                    .or_else(|| {
+                        find_node_matching(icon, &is_icon_instance, 0, 1)
+                            .and_then(|i| icon_for_instance(ctx, i))
+                    })
+            });
+        Box::new(button)
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod icon_button;
+mod icon_button_generator;
+
+pub use icon_button::*;
+pub use icon_button_generator::*;
@@ -0,0 +1,42 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, PropValue, ToJsx},
+    },
+    impl_component,
+};
+
+/// A text field with a button adornment at its end.
+#[derive(Default, Debug, Clone)]
+pub struct ComponentInputButton {
+    pub icon: Option<String>,
+    pub size: Option<String>,
+    pub disabled: bool,
+}
+impl ComponentInputButton {
+    pub fn new() -> Self {
+        ComponentInputButton { icon: None, size: None, disabled: false }
+    }
+}
+impl ToJsx for ComponentInputButton {
+    fn to_jsx(&self) -> JsxElement {
+        let mut button = JsxElement::new("IconButton")
+            .opt_prop("aria-label", self.icon.clone())
+            .flag("disabled", self.disabled);
+        if let Some(icon) = &self.icon {
+            button = button.child(JsxElement::new(icon));
+        }
+        let adornment = JsxElement::new("InputAdornment")
+            .prop("position", "end")
+            .child(button);
+        JsxElement::new("TextField")
+            .opt_prop("size", self.size.clone())
+            .flag("disabled", self.disabled)
+            .prop(
+                "InputProps",
+                PropValue::Expression(format!("{{ endAdornment: {} }}", adornment.render_inline())),
+            )
+    }
+}
+
+impl_component!(ComponentInputButton, "TextField", "/mui-components-textfield--docs");
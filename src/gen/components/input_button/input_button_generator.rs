@@ -0,0 +1,51 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::input_button::ComponentInputButton,
+        context::GenContext,
+        icons::{icon_for_component_id, icon_for_instance, is_icon_instance},
+        node_util::{find_node_matching, property_value, size_prop},
+    },
+    schema::Node,
+};
+
+#[derive(Default)]
+pub struct InputButtonGenerator {}
+
+impl InputButtonGenerator {
+    pub fn new() -> Self {
+        InputButtonGenerator {}
+    }
+}
+impl ComponentGenerator for InputButtonGenerator {
+    fn component_name(&self) -> &'static str {
+        "TextField"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "InputButton"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Size", "State", "Icon"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut input = ComponentInputButton::new();
+
+        if !ctx.is_default(node, "Size") {
+            input.size = property_value(node, "Size").and_then(size_prop);
+        }
+        input.disabled = property_value(node, "State")
+            .map(|val: String| val == "Disabled")
+            .unwrap_or(false);
+        let icon: Option<String> = property_value(node, "Icon");
+        input.icon = icon
+            .and_then(|id| icon_for_component_id(ctx, &id))
+            .or_else(|| {
+                find_node_matching(node, &is_icon_instance, 0, 2)
+                    .and_then(|icon| icon_for_instance(ctx, icon))
+            });
+        Box::new(input)
+    }
+}
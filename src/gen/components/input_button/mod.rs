@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod input_button;
+mod input_button_generator;
+
+pub use input_button::*;
+pub use input_button_generator::*;
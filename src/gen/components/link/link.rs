@@ -0,0 +1,40 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+    },
+    impl_component,
+};
+
+/// The `href` of generated links, which Figma doesn't know about.
+pub const HREF_PLACEHOLDER: &str = "#";
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentLink {
+    pub text: Option<String>,
+    pub external: bool,
+    pub disabled: bool,
+}
+impl ComponentLink {
+    pub fn new() -> Self {
+        ComponentLink { text: None, external: false, disabled: false }
+    }
+}
+impl ToJsx for ComponentLink {
+    fn to_jsx(&self) -> JsxElement {
+        let mut link = JsxElement::new("Link")
+            .prop("href", HREF_PLACEHOLDER)
+            .flag("disabled", self.disabled);
+        if self.external {
+            link = link
+                .prop("target", "_blank")
+                .prop("rel", "noopener noreferrer");
+        }
+        match &self.text {
+            Some(text) => link.text(text.clone()),
+            None => link,
+        }
+    }
+}
+
+impl_component!(ComponentLink, "Link", "/mui-components-link--docs");
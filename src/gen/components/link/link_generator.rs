@@ -0,0 +1,44 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::link::ComponentLink,
+        context::GenContext,
+        node_util::{find_node_matching, property_value, shown_characters},
+    },
+    schema::{Node, NodeType},
+};
+
+#[derive(Default)]
+pub struct LinkGenerator {}
+
+impl LinkGenerator {
+    pub fn new() -> Self {
+        LinkGenerator {}
+    }
+}
+impl ComponentGenerator for LinkGenerator {
+    fn component_name(&self) -> &'static str {
+        "Link"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Link"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Label", "Is external", "State"]
+    }
+
+    fn gen_component(&self, node: &Node, _ctx: &GenContext) -> Box<dyn Component> {
+        let mut link = ComponentLink::new();
+
+        link.text = find_node_matching(node, &|child| child.r#type == NodeType::Text, 0, 1)
+            .and_then(|text| shown_characters(node, text))
+            .or_else(|| property_value(node, "Label"));
+        link.external = property_value(node, "Is external").unwrap_or(false);
+        link.disabled = property_value(node, "State")
+            .map(|val: String| val == "Disabled")
+            .unwrap_or(false);
+        Box::new(link)
+    }
+}
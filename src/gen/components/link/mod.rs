@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod link;
+mod link_generator;
+
+pub use link::*;
+pub use link_generator::*;
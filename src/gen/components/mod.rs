@@ -1,2 +1,13 @@
 pub mod alert;
+pub mod avatar;
+pub mod badge;
+pub mod button;
 pub mod checkbox;
+pub mod container;
+pub mod icon_button;
+pub mod input_button;
+pub mod link;
+pub mod modal;
+pub mod tag;
+pub mod tag_group;
+pub mod typography;
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod modal;
+mod modal_generator;
+
+pub use modal::*;
+pub use modal_generator::*;
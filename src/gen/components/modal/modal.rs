@@ -0,0 +1,68 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, PropValue, ToJsx},
+    },
+    impl_component,
+};
+
+/// The `ModalHeader` slot of a modal.
+#[derive(Default, Debug, Clone)]
+pub struct ModalHeader {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub dismissable: bool,
+}
+impl ToJsx for ModalHeader {
+    fn to_jsx(&self) -> JsxElement {
+        let mut header =
+            JsxElement::new("ModalHeader").opt_prop("description", self.description.clone());
+        if self.dismissable {
+            header = header.prop("onClose", PropValue::Expression("() => {}".to_string()));
+        }
+        match &self.title {
+            Some(title) => header.text(title.clone()),
+            None => header,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ComponentModal {
+    pub size: Option<String>,
+    pub header: Option<ModalHeader>,
+    /// The generated body of the modal.
+    pub content: Vec<Box<dyn Component>>,
+    /// The generated buttons and controls of the action bar.
+    pub actions: Vec<Box<dyn Component>>,
+}
+impl ComponentModal {
+    pub fn new() -> Self {
+        ComponentModal { size: None, header: None, content: vec![], actions: vec![] }
+    }
+}
+impl ToJsx for ComponentModal {
+    fn to_jsx(&self) -> JsxElement {
+        let mut modal = JsxElement::new("Modal")
+            .flag("open", true)
+            .opt_prop("size", self.size.clone());
+        if let Some(header) = &self.header {
+            modal = modal.child(header.to_jsx());
+        }
+        let mut content = JsxElement::new("ModalContent");
+        for child in &self.content {
+            content = content.child(child.to_jsx());
+        }
+        modal = modal.child(content);
+        if !self.actions.is_empty() {
+            let mut actions = JsxElement::new("ModalActions");
+            for action in &self.actions {
+                actions = actions.child(action.to_jsx());
+            }
+            modal = modal.child(actions);
+        }
+        modal
+    }
+}
+
+impl_component!(ComponentModal, "Modal", "/mui-components-modal--docs");
@@ -0,0 +1,136 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::modal::{ComponentModal, ModalHeader},
+        context::GenContext,
+        diagnostic::Diagnostic,
+        node_util::{
+            exposed_instances, find_node_from_children, find_node_matching, is_shown,
+            property_scope, property_value, shown_characters,
+        },
+    },
+    schema::{Node, NodeType},
+};
+
+const SIZES: [&str; 3] = ["small", "medium", "large"];
+
+const HEADER: &str = "_ModalHeader";
+const ACTIONS: &str = "_ModalActions";
+
+#[derive(Default)]
+pub struct ModalGenerator {}
+
+impl ModalGenerator {
+    pub fn new() -> Self {
+        ModalGenerator {}
+    }
+
+    fn gen_header(&self, node: &Node) -> ModalHeader {
+        let has_description: bool = property_value(node, "Has description").unwrap_or(false);
+        ModalHeader {
+            title: find_node_from_children(node, "Title wrapper", NodeType::Frame, 0, 2)
+                .and_then(|wrapper| {
+                    find_node_matching(wrapper, &|child| child.r#type == NodeType::Text, 0, 1)
+                })
+                .and_then(|title| shown_characters(node, title)),
+            description: if has_description {
+                property_value(node, "Description text")
+            } else {
+                None
+            },
+            dismissable: property_value(node, "Dismissable").unwrap_or(false),
+        }
+    }
+
+    /// The shown instances of the action bar in order, without the frames
+    /// that lay them out.
+    fn gen_actions(
+        &self,
+        instance: &Node,
+        node: &Node,
+        ctx: &GenContext,
+        actions: &mut Vec<Box<dyn Component>>,
+    ) {
+        for child in node
+            .children
+            .iter()
+            .flatten()
+            .filter(|child| is_shown(instance, child))
+        {
+            let action = match child.r#type {
+                NodeType::Instance => ctx.generators.gen_component(child, ctx),
+                _ => None,
+            };
+            match action {
+                Some(action) => actions.push(action),
+                None => self.gen_actions(property_scope(instance, child), child, ctx, actions),
+            }
+        }
+    }
+}
+impl ComponentGenerator for ModalGenerator {
+    fn component_name(&self) -> &'static str {
+        "Modal"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Modal"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Size", "Has description", "Description text", "Dismissable"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut modal = ComponentModal::new();
+
+        // e.g. "Small (400)", the width in parentheses
+        if !ctx.is_default(node, "Size") {
+            let size: Option<String> = property_value(node, "Size");
+            modal.size = size.and_then(|size| {
+                let name = size
+                    .split('(')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_lowercase();
+                if !SIZES.contains(&name.as_str()) {
+                    ctx.report(Diagnostic::warning(node, format!("unknown modal size `{}`", size)));
+                    return None;
+                }
+                Some(name)
+            });
+        }
+
+        // The header and the actions are exposed on the modal; the content
+        // is the other children of the `_Modal` frame instance. A slot can
+        // be hidden by a property of either instance.
+        let frame = find_node_from_children(node, "_Modal", NodeType::Instance, 0, 1);
+        let shown =
+            |slot: &Node| is_shown(node, slot) && frame.is_none_or(|frame| is_shown(frame, slot));
+        let exposed = exposed_instances(node);
+        let slot = |name: &str| {
+            exposed
+                .iter()
+                .copied()
+                .find(|instance| instance.name == name)
+                .or_else(|| find_node_from_children(node, name, NodeType::Instance, 0, 2))
+                .filter(|slot| shown(slot))
+        };
+        modal.header = slot(HEADER).map(|header| self.gen_header(header));
+        if let Some(actions) = slot(ACTIONS) {
+            self.gen_actions(actions, actions, ctx, &mut modal.actions);
+        }
+        let content = frame
+            .and_then(|frame| frame.children.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|slot| shown(slot) && slot.name != HEADER && slot.name != ACTIONS);
+        for slot in content {
+            modal
+                .content
+                .extend(ctx.generators.gen_descendants(slot, ctx));
+        }
+        Box::new(modal)
+    }
+}
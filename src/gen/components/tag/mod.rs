@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod tag;
+mod tag_generator;
+
+pub use tag::*;
+pub use tag_generator::*;
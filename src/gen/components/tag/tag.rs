@@ -0,0 +1,52 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, PropValue, ToJsx},
+    },
+    impl_component,
+};
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentTag {
+    pub label: Option<String>,
+    pub size: Option<String>,
+    pub color: Option<String>,
+    pub disabled: bool,
+    pub dismissable: bool,
+    pub icon: Option<String>,
+    pub badge: Option<String>,
+}
+impl ComponentTag {
+    pub fn new() -> Self {
+        ComponentTag {
+            label: None,
+            size: None,
+            color: None,
+            disabled: false,
+            dismissable: false,
+            icon: None,
+            badge: None,
+        }
+    }
+}
+impl ToJsx for ComponentTag {
+    fn to_jsx(&self) -> JsxElement {
+        let badge = self
+            .badge
+            .clone()
+            .map(|content| JsxElement::new("Badge").prop("badgeContent", content));
+        let mut tag = JsxElement::new("Tag")
+            .opt_prop("label", self.label.clone())
+            .opt_prop("size", self.size.clone())
+            .opt_prop("color", self.color.clone())
+            .flag("disabled", self.disabled)
+            .opt_prop("icon", self.icon.as_deref().map(JsxElement::new))
+            .opt_prop("badge", badge);
+        if self.dismissable {
+            tag = tag.prop("onDelete", PropValue::Expression("() => {}".to_string()));
+        }
+        tag
+    }
+}
+
+impl_component!(ComponentTag, "Tag", "/mui-components-tag--docs");
@@ -0,0 +1,60 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::tag::ComponentTag,
+        context::GenContext,
+        icons::icon_for_component_id,
+        node_util::{find_node_from_children, property_value, variant_prop},
+    },
+    schema::{Node, NodeType},
+};
+
+#[derive(Default)]
+pub struct TagGenerator {}
+
+impl TagGenerator {
+    pub fn new() -> Self {
+        TagGenerator {}
+    }
+}
+impl ComponentGenerator for TagGenerator {
+    fn component_name(&self) -> &'static str {
+        "Tag"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "Tag"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Text", "Size", "Color", "State", "Dismissable", "Icon", "Icon instance", "Badge"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut tag = ComponentTag::new();
+
+        tag.label = property_value(node, "Text");
+        if !ctx.is_default(node, "Size") {
+            tag.size = property_value(node, "Size").and_then(variant_prop);
+        }
+        if !ctx.is_default(node, "Color") {
+            tag.color = property_value(node, "Color").and_then(variant_prop);
+        }
+        tag.disabled = property_value(node, "State")
+            .map(|val: String| val == "Disabled")
+            .unwrap_or(false);
+        tag.dismissable = property_value(node, "Dismissable").unwrap_or(false);
+
+        // `Icon` is also the prefix of `Icon instance`, match names exactly
+        if property_value(node, "Icon").unwrap_or(false) {
+            let icon: Option<String> = property_value(node, "Icon instance");
+            tag.icon = icon.and_then(|id| icon_for_component_id(ctx, &id));
+        }
+        let has_badge: bool = property_value(node, "Badge").unwrap_or(false);
+        if has_badge {
+            tag.badge = find_node_from_children(node, "Badge", NodeType::Instance, 0, 2)
+                .and_then(|badge| property_value(badge, "Text"));
+        }
+        Box::new(tag)
+    }
+}
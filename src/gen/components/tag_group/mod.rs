@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod tag_group;
+mod tag_group_generator;
+
+pub use tag_group::*;
+pub use tag_group_generator::*;
@@ -0,0 +1,30 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+    },
+    impl_component,
+};
+
+#[derive(Default)]
+pub struct ComponentTagGroup {
+    pub variant: Option<String>,
+    /// The generated `Tag`s, in the order of the design.
+    pub tags: Vec<Box<dyn Component>>,
+}
+impl ComponentTagGroup {
+    pub fn new() -> Self {
+        ComponentTagGroup { variant: None, tags: vec![] }
+    }
+}
+impl ToJsx for ComponentTagGroup {
+    fn to_jsx(&self) -> JsxElement {
+        let mut group = JsxElement::new("TagGroup").opt_prop("variant", self.variant.clone());
+        for tag in &self.tags {
+            group = group.child(tag.to_jsx());
+        }
+        group
+    }
+}
+
+impl_component!(ComponentTagGroup, "TagGroup", "/mui-components-taggroup--docs");
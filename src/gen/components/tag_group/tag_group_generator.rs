@@ -0,0 +1,53 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::{tag::TagGenerator, tag_group::ComponentTagGroup},
+        context::GenContext,
+        node_util::{is_shown, property_value, variant_prop},
+    },
+    schema::Node,
+};
+
+#[derive(Default)]
+pub struct TagGroupGenerator {}
+
+impl TagGroupGenerator {
+    pub fn new() -> Self {
+        TagGroupGenerator {}
+    }
+}
+impl ComponentGenerator for TagGroupGenerator {
+    fn component_name(&self) -> &'static str {
+        "TagGroup"
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        self.is_instance_type(node) && node.name == "TagGroup"
+    }
+
+    fn mapped_properties(&self) -> &'static [&'static str] {
+        &["Variant"]
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut group = ComponentTagGroup::new();
+
+        if !ctx.is_default(node, "Variant") {
+            group.variant = property_value(node, "Variant").and_then(variant_prop);
+        }
+        // Hidden tags are placeholders of the group and are not generated,
+        // nor are layers other than tags.
+        let tags = TagGenerator::new();
+        for child in node
+            .children
+            .iter()
+            .flatten()
+            .filter(|child| is_shown(node, child) && tags.can_gen_component(child))
+        {
+            if let Some(component) = ctx.generators.gen_component(child, ctx) {
+                group.tags.push(component);
+            }
+        }
+        Box::new(group)
+    }
+}
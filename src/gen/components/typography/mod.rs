@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod typography;
+mod typography_generator;
+
+pub use typography::*;
+pub use typography_generator::*;
@@ -0,0 +1,128 @@
+use crate::{
+    gen::{
+        component_generator::Component,
+        jsx::{JsxElement, ToJsx},
+        style::Sx,
+    },
+    impl_component,
+    schema::LineType,
+};
+
+/// A range of characters sharing the same style overrides.
+#[derive(Default, Debug, Clone)]
+pub struct TextRun {
+    pub text: String,
+    /// The overridden style, empty for characters in the base style.
+    pub sx: Sx,
+}
+#[derive(Default, Debug, Clone)]
+pub struct TextLine {
+    pub line_type: LineType,
+    pub indentation: usize,
+    pub runs: Vec<TextRun>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ComponentTypography {
+    pub variant: Option<String>,
+    pub color: Option<String>,
+    pub align: Option<String>,
+    /// Font attributes of text without a Sprout typography variant.
+    pub sx: Sx,
+    pub lines: Vec<TextLine>,
+}
+impl ComponentTypography {
+    pub fn new() -> Self {
+        ComponentTypography {
+            variant: None,
+            color: None,
+            align: None,
+            sx: Sx::new(),
+            lines: vec![],
+        }
+    }
+}
+
+fn push_runs(mut element: JsxElement, runs: &[TextRun]) -> JsxElement {
+    for run in runs {
+        element = match run.sx.to_prop() {
+            Some(sx) => element.child(
+                JsxElement::new("Box")
+                    .prop("component", "span")
+                    .prop("sx", sx)
+                    .text(&run.text),
+            ),
+            None => element.text(&run.text),
+        };
+    }
+    element
+}
+/// A list of `lines` whose first line is at the list's nesting level; deeper
+/// lines become nested lists of the preceding item.
+fn text_list(lines: &[&TextLine]) -> JsxElement {
+    let indentation = lines[0].indentation;
+    let tag = if lines[0].line_type == LineType::Ordered { "ol" } else { "ul" };
+    let mut list = JsxElement::new(tag);
+    let mut i = 0;
+    while i < lines.len() {
+        let mut item = push_runs(JsxElement::new("li"), &lines[i].runs);
+        i += 1;
+        let nested = lines[i..]
+            .iter()
+            .take_while(|line| line.indentation > indentation)
+            .count();
+        if nested > 0 {
+            item = item.child(text_list(&lines[i..i + nested]));
+            i += nested;
+        }
+        list = list.child(item);
+    }
+    list
+}
+
+impl ToJsx for ComponentTypography {
+    fn to_jsx(&self) -> JsxElement {
+        let has_lists = self
+            .lines
+            .iter()
+            .any(|line| line.line_type != LineType::None);
+        let mut typography = JsxElement::new("Typography")
+            .opt_prop("variant", self.variant.clone())
+            .opt_prop("color", self.color.clone())
+            .opt_prop("align", self.align.clone())
+            .opt_prop("sx", self.sx.to_prop());
+        if has_lists {
+            // lists cannot be nested in the default <p>
+            typography = typography.prop("component", "div");
+        }
+        // a plain line follows another one after a break, lists are blocks
+        let mut after_line = false;
+        let mut i = 0;
+        while i < self.lines.len() {
+            let line = &self.lines[i];
+            if line.line_type == LineType::None {
+                if after_line {
+                    typography = typography.child(JsxElement::new("br"));
+                }
+                typography = push_runs(typography, &line.runs);
+                after_line = true;
+                i += 1;
+                continue;
+            }
+            after_line = false;
+            let count = 1 + self.lines[i + 1..]
+                .iter()
+                .take_while(|l| {
+                    l.line_type != LineType::None
+                        && (l.indentation > line.indentation || l.line_type == line.line_type)
+                })
+                .count();
+            let items: Vec<&TextLine> = self.lines[i..i + count].iter().collect();
+            i += items.len();
+            typography = typography.child(text_list(&items));
+        }
+        typography
+    }
+}
+
+impl_component!(ComponentTypography, "Typography", "/mui-components-typography--docs");
@@ -0,0 +1,114 @@
+use crate::{
+    gen::{
+        component_generator::{Component, ComponentGenerator},
+        components::typography::{ComponentTypography, TextLine, TextRun},
+        context::GenContext,
+        style::{paint_color, typography_sx},
+    },
+    schema::{LineType, Node, NodeType, TextAlignHorizontal},
+};
+
+/// The Sprout typography variant of a text style token, e.g. `label-m-emphasized`
+/// for the style `sprout/label/font/m-emphasized`.
+fn typography_variant(token: &str) -> String {
+    let segments: Vec<&str> = token
+        .split('.')
+        .filter(|segment| *segment != "font")
+        .collect();
+    segments.join("-")
+}
+
+#[derive(Default)]
+pub struct TypographyGenerator {}
+
+impl TypographyGenerator {
+    pub fn new() -> Self {
+        TypographyGenerator {}
+    }
+
+    /// Splits the characters of `node` into lines, and each line into runs of
+    /// characters with the same style override.
+    fn lines(&self, node: &Node, ctx: &GenContext) -> Vec<TextLine> {
+        let characters = node.characters.as_deref().unwrap_or_default();
+        let overrides = node
+            .character_style_overrides
+            .as_deref()
+            .unwrap_or_default();
+        let line_types = node.line_types.as_deref().unwrap_or_default();
+        let indentations = node.line_indentations.as_deref().unwrap_or_default();
+
+        let mut lines = vec![];
+        let mut offset = 0;
+        for (index, text) in characters.split('\n').enumerate() {
+            let mut line = TextLine {
+                line_type: line_types
+                    .get(index)
+                    .copied()
+                    .filter(|line_type| *line_type != LineType::Other)
+                    .unwrap_or_default(),
+                indentation: indentations.get(index).copied().unwrap_or_default(),
+                runs: vec![],
+            };
+            let mut current: Option<usize> = None;
+            for c in text.chars() {
+                let style_id = overrides.get(offset).copied().unwrap_or(0);
+                offset += c.len_utf16();
+                if current != Some(style_id) {
+                    let sx = node
+                        .style_override_table
+                        .as_ref()
+                        .and_then(|table| table.get(&style_id.to_string()))
+                        .map(|style| typography_sx(style, ctx.tokens))
+                        .unwrap_or_default();
+                    line.runs.push(TextRun { text: String::new(), sx });
+                    current = Some(style_id);
+                }
+                if let Some(run) = line.runs.last_mut() {
+                    run.text.push(c);
+                }
+            }
+            // the line break
+            offset += 1;
+            lines.push(line);
+        }
+        lines
+    }
+}
+impl ComponentGenerator for TypographyGenerator {
+    fn component_name(&self) -> &'static str {
+        "Typography"
+    }
+
+    fn is_library_component(&self) -> bool {
+        false
+    }
+
+    fn can_gen_component(&self, node: &Node) -> bool {
+        node.r#type == NodeType::Text
+    }
+
+    fn gen_component(&self, node: &Node, ctx: &GenContext) -> Box<dyn Component> {
+        let mut typography = ComponentTypography::new();
+
+        typography.variant = ctx.tokens.style_token(node, "text").map(typography_variant);
+        if let Some(style) = &node.style {
+            if typography.variant.is_none() {
+                typography.sx = typography_sx(style, ctx.tokens);
+            }
+            typography.align = match style.text_align_horizontal {
+                Some(TextAlignHorizontal::Center) => Some("center".to_string()),
+                Some(TextAlignHorizontal::Right) => Some("right".to_string()),
+                Some(TextAlignHorizontal::Justified) => Some("justify".to_string()),
+                _ => None,
+            };
+        }
+        typography.color = ctx.tokens.fill_token(node).map(str::to_string).or_else(|| {
+            node.fills
+                .as_deref()
+                .and_then(|fills| paint_color(fills, ctx.tokens))
+        });
+        typography.lines = self.lines(node, ctx);
+
+        Box::new(typography)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{
+    gen::{
+        component_generator::Generators, diagnostic::Diagnostic, node_util::find_property,
+        tokens::TokenResolver, validation::PropertyDefinitions,
+    },
+    schema::{ComponentPropertyDefinition, File, Node},
+};
+use std::cell::RefCell;
+
+/// State shared by the generators while generating a node: the parsed file,
+/// the generators for nested nodes, the library definitions, the design tokens
+/// and the diagnostics reported so far.
+pub struct GenContext<'a> {
+    pub file: &'a File,
+    pub generators: &'a Generators,
+    pub definitions: &'a PropertyDefinitions,
+    pub tokens: &'a TokenResolver,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+}
+impl<'a> GenContext<'a> {
+    pub fn new(
+        file: &'a File,
+        generators: &'a Generators,
+        definitions: &'a PropertyDefinitions,
+        tokens: &'a TokenResolver,
+    ) -> Self {
+        GenContext { file, generators, definitions, tokens, diagnostics: RefCell::new(vec![]) }
+    }
+    pub fn report(&self, diagnostic: Diagnostic) {
+        self.diagnostics.borrow_mut().push(diagnostic);
+    }
+    pub fn take_diagnostics(&self) -> Vec<Diagnostic> {
+        self.diagnostics.take()
+    }
+    pub fn property_definition(
+        &self,
+        node: &Node,
+        name: &str,
+    ) -> Option<&'a ComponentPropertyDefinition> {
+        self.definitions.find(self.file, node, name)
+    }
+    /// Whether the instance keeps the library default for the property `name`.
+    /// Sprout's Figma library mirrors the defaults of the React components, so
+    /// generators can leave such props out of the emitted code.
+    pub fn is_default(&self, node: &Node, name: &str) -> bool {
+        match (find_property(node, name), self.property_definition(node, name)) {
+            (Some((_, property)), Some(definition)) => {
+                *property.value() == definition.default_value
+            },
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    gen::{context::GenContext, query},
+    schema::{File, Node, NodeType},
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+/// How well the instances of one component set are converted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentCoverage {
+    /// The name of the component set, or of the component without a set.
+    pub name: String,
+    pub instances: usize,
+    /// The components generated for the instances, e.g. `Button`.
+    pub generated_as: BTreeSet<String>,
+    /// The instances a generator matched.
+    pub generated: usize,
+    /// The generated instances without diagnostics.
+    pub clean: usize,
+    /// The properties of the instances no generator maps.
+    pub unmapped_properties: BTreeSet<String>,
+}
+impl ComponentCoverage {
+    pub fn has_generator(&self) -> bool {
+        self.generated > 0
+    }
+}
+
+/// The component sets used in a file and whether code is generated for them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageReport {
+    pub file: String,
+    /// By name, the component sets with a generator first.
+    pub components: Vec<ComponentCoverage>,
+}
+
+/// The name of the property `key` without its `#id` suffix.
+fn property_name(key: &str) -> &str {
+    key.split_once('#').map_or(key, |(name, _)| name)
+}
+fn join(names: &BTreeSet<String>) -> String {
+    names
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+/// The name the instance `node` is grouped by.
+fn group_name(file: &File, node: &Node) -> String {
+    query::component_set_name(file, node)
+        .or_else(|| {
+            let component = file.components.get(node.component_id.as_deref()?)?;
+            Some(component.name.as_str())
+        })
+        .unwrap_or(&node.name)
+        .to_string()
+}
+
+/// Generates every instance of `ctx.file` and reports the outcome per
+/// component set.
+pub fn coverage_report(ctx: &GenContext) -> CoverageReport {
+    let file = ctx.file;
+    let mut groups: BTreeMap<String, ComponentCoverage> = BTreeMap::new();
+    let instances =
+        query::descendants(&file.document).filter(|node| node.r#type == NodeType::Instance);
+    for node in instances {
+        let name = group_name(file, node);
+        let group = groups
+            .entry(name.clone())
+            .or_insert_with(|| ComponentCoverage { name, ..Default::default() });
+        group.instances += 1;
+
+        let mapped = ctx
+            .generators
+            .generator_for(node)
+            .map(|gen| gen.mapped_properties());
+        let properties = node.component_properties.iter().flatten();
+        for key in properties.map(|(key, _)| property_name(key)) {
+            if !mapped.is_some_and(|mapped| mapped.contains(&key)) {
+                group.unmapped_properties.insert(key.to_string());
+            }
+        }
+
+        let component = ctx.generators.gen_component(node, ctx);
+        let diagnostics = ctx.take_diagnostics();
+        if let Some(component) = component {
+            group.generated += 1;
+            group.generated_as.insert(component.name());
+            if diagnostics.is_empty() {
+                group.clean += 1;
+            }
+        }
+    }
+
+    let mut components: Vec<ComponentCoverage> = groups.into_values().collect();
+    components.sort_by_key(|component| !component.has_generator());
+    CoverageReport { file: file.name.clone(), components }
+}
+
+impl CoverageReport {
+    pub fn instances(&self) -> usize {
+        self.components
+            .iter()
+            .map(|component| component.instances)
+            .sum()
+    }
+    pub fn generated(&self) -> usize {
+        self.components
+            .iter()
+            .map(|component| component.generated)
+            .sum()
+    }
+    pub fn clean(&self) -> usize {
+        self.components
+            .iter()
+            .map(|component| component.clean)
+            .sum()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// The report as a plain text table, one component set per row.
+    pub fn to_table(&self) -> String {
+        let headers = ["Component", "Instances", "Generator", "Generated", "Clean", "Unmapped"];
+        let rows: Vec<[String; 6]> = self
+            .components
+            .iter()
+            .map(|component| {
+                [
+                    component.name.clone(),
+                    component.instances.to_string(),
+                    join(&component.generated_as),
+                    component.generated.to_string(),
+                    component.clean.to_string(),
+                    join(&component.unmapped_properties),
+                ]
+            })
+            .collect();
+        let mut widths = headers.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut table = String::new();
+        let mut push_row = |cells: &[&str]| {
+            let line: Vec<String> = cells
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect();
+            writeln!(table, "{}", line.join("  ").trim_end()).unwrap();
+        };
+        push_row(&headers);
+        let rule = widths.map(|width| "-".repeat(width));
+        push_row(&rule.each_ref().map(String::as_str));
+        for row in &rows {
+            push_row(&row.each_ref().map(String::as_str));
+        }
+        let total = self.instances();
+        writeln!(
+            table,
+            "\n{} instances, {} generated ({}%), {} without diagnostics",
+            total,
+            self.generated(),
+            (self.generated() * 100).checked_div(total).unwrap_or(0),
+            self.clean()
+        )
+        .unwrap();
+        table
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    gen::{diagnostic::Diagnostic, query::find_by_id},
+    schema::{Node, Overrides},
+};
+
+/// Overridden fields that generated props express or that don't show in code.
+const EXPRESSED_FIELDS: [&str; 5] =
+    ["componentProperties", "name", "exportSettings", "pluginData", "sharedPluginData"];
+
+/// A layer of an instance that the designer customized beyond its props.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Customization {
+    pub layer_id: String,
+    pub layer_name: String,
+    /// The overridden fields that Sprout props cannot express.
+    pub fields: Vec<String>,
+}
+
+/// Whether the override of `field` is bound to a component property, and so
+/// expressed by the generated props.
+fn is_expressed(layer: Option<&Node>, field: &str) -> bool {
+    if EXPRESSED_FIELDS.contains(&field) {
+        return true;
+    }
+    let bound = match field {
+        "characters" => "characters",
+        "visible" => "visible",
+        "mainComponent" | "componentId" => "mainComponent",
+        _ => return false,
+    };
+    layer
+        .and_then(|layer| layer.component_property_references.as_ref())
+        .is_some_and(|references| references.contains_key(bound))
+}
+
+fn customization(instance: &Node, overrides: &Overrides) -> Option<Customization> {
+    let layer = find_by_id(instance, &overrides.id);
+    let fields: Vec<String> = overrides
+        .overridden_fields
+        .iter()
+        .filter(|field| !is_expressed(layer, field))
+        .cloned()
+        .collect();
+    if fields.is_empty() {
+        return None;
+    }
+    Some(Customization {
+        layer_id: overrides.id.clone(),
+        layer_name: layer.map(|layer| layer.name.clone()).unwrap_or_default(),
+        fields,
+    })
+}
+
+/// The customizations of the instance `node`, from its `overrides`.
+pub fn customizations(node: &Node) -> Vec<Customization> {
+    node.overrides
+        .iter()
+        .flatten()
+        .filter_map(|overrides| customization(node, overrides))
+        .collect()
+}
+
+/// The customization report of the instance `node`: a warning for every layer
+/// whose overrides the generated code doesn't reproduce.
+pub fn customization_report(node: &Node) -> Vec<Diagnostic> {
+    customizations(node)
+        .into_iter()
+        .map(|customization| {
+            let layer = if customization.layer_id == node.id {
+                "the instance".to_string()
+            } else {
+                format!("layer `{}` ({})", customization.layer_name, customization.layer_id)
+            };
+            Diagnostic::warning(
+                node,
+                format!(
+                    "{} overrides {} which the generated props don't express",
+                    layer,
+                    customization
+                        .fields
+                        .iter()
+                        .map(|field| format!("`{}`", field))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        })
+        .collect()
+}
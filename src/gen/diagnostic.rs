@@ -0,0 +1,45 @@
+use crate::schema::Node;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+/// A problem found while validating or generating a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub node_id: String,
+    pub node_name: String,
+    pub message: String,
+}
+impl Diagnostic {
+    pub fn new(severity: Severity, node: &Node, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            node_id: node.id.clone(),
+            node_name: node.name.clone(),
+            message: message.into(),
+        }
+    }
+    pub fn warning(node: &Node, message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, node, message)
+    }
+    pub fn error(node: &Node, message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, node, message)
+    }
+}
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({}): {}", self.severity, self.node_name, self.node_id, self.message)
+    }
+}
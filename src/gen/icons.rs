@@ -0,0 +1,40 @@
+use crate::{
+    gen::context::GenContext,
+    schema::{Node, NodeType},
+};
+
+const ICON_PREFIX: &str = "icons/";
+
+/// The Sprout icon component of a Figma icon component, e.g. `Download` for
+/// `icons/outline/download` and `ClosingX16` for `icons/outline/closing_X_16`.
+pub fn icon_component(figma_name: &str) -> Option<String> {
+    let name = figma_name.strip_prefix(ICON_PREFIX)?.rsplit('/').next()?;
+    let component: String = name
+        .split(['_', '-', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    (!component.is_empty()).then_some(component)
+}
+/// The icon component of the component `component_id`, e.g. the value of an
+/// INSTANCE_SWAP property.
+pub fn icon_for_component_id(ctx: &GenContext, component_id: &str) -> Option<String> {
+    icon_component(&ctx.file.components.get(component_id)?.name)
+}
+/// Whether `node` is an instance of an icon component.
+pub fn is_icon_instance(node: &Node) -> bool {
+    node.r#type == NodeType::Instance && node.name.starts_with(ICON_PREFIX)
+}
+/// The icon component of an icon INSTANCE.
+pub fn icon_for_instance(ctx: &GenContext, node: &Node) -> Option<String> {
+    node.component_id
+        .as_deref()
+        .and_then(|id| icon_for_component_id(ctx, id))
+        .or_else(|| icon_component(&node.name))
+}
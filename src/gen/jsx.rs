@@ -0,0 +1,175 @@
+use std::fmt::Write;
+
+/// The value of a JSX attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropValue {
+    String(String),
+    Bool(bool),
+    Number(f64),
+    /// A raw JavaScript expression, emitted between braces as is.
+    Expression(String),
+    /// A JSX element passed as a prop, e.g. `control={<Checkbox />}`.
+    Element(Box<JsxElement>),
+}
+impl From<&str> for PropValue {
+    fn from(value: &str) -> Self {
+        PropValue::String(value.to_string())
+    }
+}
+impl From<String> for PropValue {
+    fn from(value: String) -> Self {
+        PropValue::String(value)
+    }
+}
+impl From<bool> for PropValue {
+    fn from(value: bool) -> Self {
+        PropValue::Bool(value)
+    }
+}
+impl From<f64> for PropValue {
+    fn from(value: f64) -> Self {
+        PropValue::Number(value)
+    }
+}
+impl From<JsxElement> for PropValue {
+    fn from(value: JsxElement) -> Self {
+        PropValue::Element(Box::new(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsxNode {
+    Element(JsxElement),
+    Text(String),
+}
+
+/// A JSX element tree emitted by a generated component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsxElement {
+    pub tag: String,
+    pub props: Vec<(String, PropValue)>,
+    pub children: Vec<JsxNode>,
+}
+impl JsxElement {
+    pub fn new(tag: &str) -> Self {
+        JsxElement { tag: tag.to_string(), props: vec![], children: vec![] }
+    }
+    pub fn prop(mut self, name: &str, value: impl Into<PropValue>) -> Self {
+        self.props.push((name.to_string(), value.into()));
+        self
+    }
+    /// Adds the prop only when a value is present.
+    pub fn opt_prop<T: Into<PropValue>>(self, name: &str, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.prop(name, value),
+            None => self,
+        }
+    }
+    /// Adds a boolean prop only when it is set, e.g. `disabled`.
+    pub fn flag(self, name: &str, value: bool) -> Self {
+        if value {
+            self.prop(name, true)
+        } else {
+            self
+        }
+    }
+    pub fn child(mut self, child: JsxElement) -> Self {
+        self.children.push(JsxNode::Element(child));
+        self
+    }
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.children.push(JsxNode::Text(text.into()));
+        self
+    }
+
+    /// Renders the element as indented, multi-line JSX.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+        out
+    }
+    /// Renders the element on a single line, as used for element props.
+    pub fn render_inline(&self) -> String {
+        let mut out = String::new();
+        self.write_open_tag(&mut out);
+        if self.children.is_empty() {
+            out.push_str(" />");
+        } else {
+            out.push('>');
+            for child in &self.children {
+                match child {
+                    JsxNode::Element(element) => out.push_str(&element.render_inline()),
+                    JsxNode::Text(text) => out.push_str(&escape_text(text)),
+                }
+            }
+            let _ = write!(out, "</{}>", self.tag);
+        }
+        out
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        out.push_str(&indent);
+        self.write_open_tag(out);
+        if self.children.is_empty() {
+            out.push_str(" />\n");
+            return;
+        }
+        out.push_str(">\n");
+        for child in &self.children {
+            match child {
+                JsxNode::Element(element) => element.write(out, depth + 1),
+                JsxNode::Text(text) => {
+                    let _ = writeln!(out, "{}  {}", indent, escape_text(text));
+                },
+            }
+        }
+        let _ = writeln!(out, "{}</{}>", indent, self.tag);
+    }
+    fn write_open_tag(&self, out: &mut String) {
+        let _ = write!(out, "<{}", self.tag);
+        for (name, value) in &self.props {
+            match value {
+                PropValue::String(value) if !value.contains('"') => {
+                    let _ = write!(out, " {}=\"{}\"", name, value);
+                },
+                PropValue::String(value) => {
+                    let _ = write!(out, " {}={{{}}}", name, js_string(value));
+                },
+                PropValue::Bool(true) => {
+                    let _ = write!(out, " {}", name);
+                },
+                PropValue::Bool(false) => {
+                    let _ = write!(out, " {}={{false}}", name);
+                },
+                PropValue::Number(value) => {
+                    let _ = write!(out, " {}={{{}}}", name, value);
+                },
+                PropValue::Expression(expression) => {
+                    let _ = write!(out, " {}={{{}}}", name, expression);
+                },
+                PropValue::Element(element) => {
+                    let _ = write!(out, " {}={{{}}}", name, element.render_inline());
+                },
+            }
+        }
+    }
+}
+
+/// Text children containing JSX syntax characters, or whitespace that JSX
+/// would trim, are emitted as string expressions.
+fn escape_text(text: &str) -> String {
+    let trimmed = text.trim() != text;
+    if trimmed || text.contains(['{', '}', '<', '>']) {
+        format!("{{{}}}", js_string(text))
+    } else {
+        text.to_string()
+    }
+}
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+pub trait ToJsx {
+    fn to_jsx(&self) -> JsxElement;
+}
@@ -1,3 +1,15 @@
 pub mod component_generator;
 pub mod components;
+pub mod context;
+pub mod coverage;
+pub mod customization;
+pub mod diagnostic;
+pub mod icons;
+pub mod jsx;
 pub mod node_util;
+pub mod query;
+pub mod style;
+pub mod token_export;
+pub mod tokens;
+pub mod validation;
+pub mod variables;
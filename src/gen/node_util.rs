@@ -1,66 +1,188 @@
-use crate::{
-    schema::{File, Node, NodeType},
-    ui::tree::TreeNode,
-};
+use crate::schema::{ComponentProperty, File, InstancePath, Node, NodeId, NodePath, NodeType};
 use serde_json::from_str;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 pub fn convert_json_to_figma(json: String) -> Result<File, String> {
-    let file = from_str(&json).map_err(|e| e.to_string());
-    file
-}
-pub fn find_figma_node(file: &Arc<File>, paths: String) -> Option<&Node> {
-    let path_list: Vec<&str> = paths.split("|").collect();
-    if path_list[0] == file.document.id {
-        let new_path: Vec<&str> = path_list.into_iter().skip(1).collect();
-        if let Some(pages) = &file.document.children {
-            for page in pages {
-                if let Some(node) = find_node(page, new_path.clone()) {
-                    return Some(node);
-                }
-            }
-        }
+    from_str(&json).map_err(|e| e.to_string())
+}
+/// Finds the node at the end of `path`, which starts with the document id.
+pub fn find_figma_node<'a>(file: &'a File, path: &NodePath) -> Option<&'a Node> {
+    let (document_id, ids) = path.ids().split_first()?;
+    if *document_id != file.document.id {
+        return None;
     }
+    let mut node = &file.document;
+    for id in ids {
+        node = node
+            .children
+            .iter()
+            .flatten()
+            .find(|child| *id == child.id)?;
+    }
+    Some(node)
+}
 
-    None
+/// An index of the nodes of a file by id, built once after parsing.
+///
+/// Every id maps to the child positions leading to its node, so a lookup
+/// indexes straight into the tree without searching it.
+pub struct NodeIndex {
+    file: Arc<File>,
+    positions: HashMap<NodeId, Vec<usize>>,
 }
-fn find_node<'a>(node: &'a Node, paths: Vec<&str>) -> Option<&'a Node> {
-    let path_len = paths.len();
-    if path_len > 0 {
-        if node.id == paths[0] {
-            if path_len == 1 {
-                return Some(node);
-            } else {
-                if let Some(children) = &node.children {
-                    let new_paths: Vec<&str> = paths.into_iter().skip(1).collect();
-                    for child in children {
-                        if let Some(found) = find_node(child, new_paths.clone()) {
-                            return Some(found);
-                        }
-                    }
-                }
+impl NodeIndex {
+    pub fn new(file: Arc<File>) -> Self {
+        let mut positions = HashMap::new();
+        index_node(&file.document, &mut vec![], &mut positions);
+        NodeIndex { file, positions }
+    }
+    pub fn file(&self) -> &Arc<File> {
+        &self.file
+    }
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+    pub fn contains(&self, id: &NodeId) -> bool {
+        self.positions.contains_key(id)
+    }
+    /// The node `id`.
+    pub fn get(&self, id: &NodeId) -> Option<&Node> {
+        let mut node = &self.file.document;
+        for &position in self.positions.get(id)? {
+            node = node.children.as_ref()?.get(position)?;
+        }
+        Some(node)
+    }
+    /// The ancestors of the node `id`, the parent first.
+    pub fn ancestors(&self, id: &NodeId) -> Vec<&Node> {
+        let Some(positions) = self.positions.get(id) else {
+            return vec![];
+        };
+        let mut node = &self.file.document;
+        let mut ancestors = vec![];
+        for &position in positions {
+            ancestors.push(node);
+            match node
+                .children
+                .as_ref()
+                .and_then(|children| children.get(position))
+            {
+                Some(child) => node = child,
+                None => return vec![],
             }
         }
+        ancestors.reverse();
+        ancestors
     }
-    None
+    pub fn parent(&self, id: &NodeId) -> Option<&Node> {
+        self.ancestors(id).into_iter().next()
+    }
+    /// The path from the document down to the node `id`.
+    pub fn path(&self, id: &NodeId) -> Option<NodePath> {
+        let mut node = &self.file.document;
+        let mut path = NodePath::new().child(node.id.as_str());
+        for &position in self.positions.get(id)? {
+            node = node.children.as_ref()?.get(position)?;
+            path = path.child(node.id.as_str());
+        }
+        Some(path)
+    }
+}
+fn index_node(node: &Node, position: &mut Vec<usize>, positions: &mut HashMap<NodeId, Vec<usize>>) {
+    positions.insert(NodeId::new(node.id.as_str()), position.clone());
+    for (index, child) in node.children.iter().flatten().enumerate() {
+        position.push(index);
+        index_node(child, position, positions);
+        position.pop();
+    }
+}
+
+/// Finds the node of `path` in the tree of `node`: the outermost instance
+/// first, then each nested layer inside the instance found before.
+pub fn find_instance_node<'a>(node: &'a Node, path: &InstancePath) -> Option<&'a Node> {
+    let mut found = find_node_by_id(node, path.root(), &|_| true)?;
+    for prefix in path.ancestors_and_self().skip(1) {
+        // the layers of an instance all have ids of the form `I<instance>;…`
+        let instance = format!("{};", found.id.trim_start_matches('I'));
+        let id = prefix.to_string();
+        found = find_node_by_id(found, &id, &|child| {
+            child.id.trim_start_matches('I').starts_with(&instance)
+        })?;
+    }
+    Some(found)
+}
+fn find_node_by_id<'a>(
+    node: &'a Node,
+    id: &str,
+    descend: &dyn Fn(&Node) -> bool,
+) -> Option<&'a Node> {
+    if node.id == id {
+        return Some(node);
+    }
+    node.children
+        .iter()
+        .flatten()
+        .filter(|child| descend(child))
+        .find_map(|child| find_node_by_id(child, id, descend))
+}
+
+/// The nested instances exposed on the instance `node`, in order.
+pub fn exposed_instances(node: &Node) -> Vec<&Node> {
+    node.exposed_instances
+        .iter()
+        .flatten()
+        .filter_map(|id| id.parse::<InstancePath>().ok())
+        .filter_map(|path| find_instance_node(node, &path))
+        .collect()
 }
 
+/// Finds the shown descendant of `node` named `node_name` down to
+/// `max_depth` levels.
 pub fn find_node_from_children<'a>(
     node: &'a Node,
     node_name: &str,
     node_type: NodeType,
     cur_depth: usize,
     max_depth: usize,
+) -> Option<&'a Node> {
+    find_node_matching(
+        node,
+        &|child| child.r#type == node_type && child.name == node_name,
+        cur_depth,
+        max_depth,
+    )
+}
+
+/// Depth-first search of the shown descendants of `node` down to `max_depth`
+/// levels. Hidden layers and their children are skipped.
+pub fn find_node_matching<'a>(
+    node: &'a Node,
+    predicate: &dyn Fn(&Node) -> bool,
+    cur_depth: usize,
+    max_depth: usize,
+) -> Option<&'a Node> {
+    find_shown_node(node, node, predicate, cur_depth, max_depth)
+}
+fn find_shown_node<'a>(
+    instance: &Node,
+    node: &'a Node,
+    predicate: &dyn Fn(&Node) -> bool,
+    cur_depth: usize,
+    max_depth: usize,
 ) -> Option<&'a Node> {
     if let Some(children) = &node.children {
         let cur_depth = cur_depth + 1;
         if cur_depth <= max_depth {
-            for child in children {
-                if child.r#type == node_type && child.name == node_name {
+            for child in children.iter().filter(|child| is_shown(instance, child)) {
+                if predicate(child) {
                     return Some(child);
                 }
+                let instance = property_scope(instance, child);
                 if let Some(value) =
-                    find_node_from_children(child, node_name, node_type, cur_depth, max_depth)
+                    find_shown_node(instance, child, predicate, cur_depth, max_depth)
                 {
                     return Some(value);
                 }
@@ -69,3 +191,83 @@ pub fn find_node_from_children<'a>(
     }
     None
 }
+
+/// The instance whose component properties the children of `node` refer to:
+/// `node` itself if it is an instance, otherwise the enclosing `instance`.
+pub fn property_scope<'a>(instance: &'a Node, node: &'a Node) -> &'a Node {
+    match node.r#type {
+        NodeType::Instance => node,
+        _ => instance,
+    }
+}
+
+fn referenced_property<'a>(
+    instance: &'a Node,
+    node: &Node,
+    field: &str,
+) -> Option<&'a ComponentProperty> {
+    let key = node.component_property_references.as_ref()?.get(field)?;
+    instance.component_properties.as_ref()?.get(key)
+}
+
+/// Whether the layer `node` of `instance` is shown: it is visible, and the
+/// boolean property its visibility is bound to, if any, is on.
+pub fn is_shown(instance: &Node, node: &Node) -> bool {
+    node.is_visible()
+        && referenced_property(instance, node, "visible")
+            .and_then(Option::<bool>::from)
+            .unwrap_or(true)
+}
+
+/// The text of the layer `node` of `instance`, taken from the text property
+/// it is bound to, if any.
+pub fn shown_characters(instance: &Node, node: &Node) -> Option<String> {
+    referenced_property(instance, node, "characters")
+        .and_then(Option::<String>::from)
+        .or_else(|| node.characters.clone())
+}
+
+/// The id of the component shown by the nested instance `node` of
+/// `instance`, taken from the instance swap property it is bound to, if any.
+pub fn main_component_id(instance: &Node, node: &Node) -> Option<String> {
+    referenced_property(instance, node, "mainComponent")
+        .and_then(Option::<String>::from)
+        .or_else(|| node.component_id.clone())
+}
+
+/// The prop value of a variant such as `Size` or `Variant`: the lower-cased
+/// value, or `None` for `Default`, which is the default of the Sprout prop.
+pub fn variant_prop(value: String) -> Option<String> {
+    let value = value.to_lowercase();
+    (value != "default").then_some(value)
+}
+
+/// The prop value of a `Size` variant, e.g. `xsmall` for "Extra small", or
+/// `None` for `Default`.
+pub fn size_prop(value: String) -> Option<String> {
+    variant_prop(value).map(|size| size.replace("extra ", "x").replace(' ', "-"))
+}
+
+/// Whether a component property key such as `Label#18495:38` refers to the
+/// property `name`. Variant keys carry no `#id` suffix.
+pub fn is_property_name(key: &str, name: &str) -> bool {
+    key == name
+        || key
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.starts_with('#'))
+}
+pub fn find_property<'a>(node: &'a Node, name: &str) -> Option<(&'a str, &'a ComponentProperty)> {
+    node.component_properties
+        .as_ref()?
+        .iter()
+        .find(|(key, _)| is_property_name(key, name))
+        .map(|(key, property)| (key.as_str(), property))
+}
+/// The value of the property `name` of `node`, matched by its exact name so
+/// that `Title` doesn't find `Title text#12:3`.
+pub fn property_value<T>(node: &Node, name: &str) -> Option<T>
+where
+    for<'a> Option<T>: From<&'a ComponentProperty>,
+{
+    find_property(node, name).and_then(|(_, property)| property.into())
+}
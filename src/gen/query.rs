@@ -0,0 +1,371 @@
+use crate::schema::{File, Node, NodeType};
+use std::collections::VecDeque;
+
+/// The descendants of a node in depth-first pre-order, the node excluded.
+pub struct DepthFirst<'a> {
+    stack: Vec<&'a Node>,
+}
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().flatten().rev());
+        Some(node)
+    }
+}
+/// The descendants of a node level by level, the node excluded.
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<&'a Node>,
+}
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.children.iter().flatten());
+        Some(node)
+    }
+}
+
+pub fn descendants(node: &Node) -> DepthFirst<'_> {
+    DepthFirst { stack: node.children.iter().flatten().rev().collect() }
+}
+pub fn descendants_breadth_first(node: &Node) -> BreadthFirst<'_> {
+    BreadthFirst { queue: node.children.iter().flatten().collect() }
+}
+
+/// The node `id` in the tree of `node`.
+pub fn find_by_id<'a>(node: &'a Node, id: &str) -> Option<&'a Node> {
+    if node.id == id {
+        return Some(node);
+    }
+    descendants(node).find(|descendant| descendant.id == id)
+}
+pub fn find_by_type(node: &Node, node_type: NodeType) -> Vec<&Node> {
+    descendants(node)
+        .filter(|descendant| descendant.r#type == node_type)
+        .collect()
+}
+/// The descendants whose name matches `pattern`, where `*` matches any text
+/// and `?` any character.
+pub fn find_by_name<'a>(node: &'a Node, pattern: &str) -> Vec<&'a Node> {
+    descendants(node)
+        .filter(|descendant| glob_match(pattern, &descendant.name))
+        .collect()
+}
+/// The instances of the variants of the component set named `set_name`.
+pub fn find_by_component_set<'a>(file: &File, node: &'a Node, set_name: &str) -> Vec<&'a Node> {
+    descendants(node)
+        .filter(|descendant| component_set_name(file, descendant) == Some(set_name))
+        .collect()
+}
+/// The name of the component set of the instance `node`.
+pub fn component_set_name<'a>(file: &'a File, node: &Node) -> Option<&'a str> {
+    let component = file.components.get(node.component_id.as_deref()?)?;
+    let set = file
+        .component_sets
+        .get(component.component_set_id.as_deref()?)?;
+    Some(&set.name)
+}
+
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // the position of the last `*` and of the text it matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            },
+            Some('?') => {
+                p += 1;
+                t += 1;
+            },
+            Some(c) if *c == text[t] => {
+                p += 1;
+                t += 1;
+            },
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operator {
+    Equals,
+    Prefix,
+    Contains,
+    Glob,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AttributeName {
+    Name,
+    Id,
+    Type,
+    ComponentSet,
+}
+#[derive(Debug, Clone, PartialEq)]
+struct Attribute {
+    name: AttributeName,
+    operator: Operator,
+    value: String,
+}
+/// A node type and attribute filters, e.g. `INSTANCE[name="Checkbox"]`.
+#[derive(Debug, Clone, PartialEq)]
+struct Compound {
+    node_type: Option<NodeType>,
+    attributes: Vec<Attribute>,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// A CSS-like selector over the node tree, e.g. `FRAME > INSTANCE[name="Checkbox"]`.
+///
+/// Compounds are a node type (or `*`) followed by `[attribute op "value"]`
+/// filters on `name`, `id`, `type` or `componentSet`, with `=` (equals), `^=`
+/// (starts with), `*=` (contains) or `~=` (glob). Compounds are combined with
+/// `>` for children and whitespace for descendants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    /// The last compound matches the selected node, the others its ancestors.
+    compounds: Vec<Compound>,
+    /// The combinator before each compound but the first.
+    combinators: Vec<Combinator>,
+}
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Self, String> {
+        let mut parser = Parser { chars: selector.chars().collect(), pos: 0 };
+        let mut compounds = vec![parser.compound()?];
+        let mut combinators = vec![];
+        loop {
+            let had_space = parser.skip_whitespace();
+            if parser.peek().is_none() {
+                break;
+            }
+            if parser.peek() == Some('>') {
+                parser.pos += 1;
+                parser.skip_whitespace();
+                combinators.push(Combinator::Child);
+            } else if had_space {
+                combinators.push(Combinator::Descendant);
+            } else {
+                return Err(parser.error("expected a combinator"));
+            }
+            compounds.push(parser.compound()?);
+        }
+        Ok(Selector { compounds, combinators })
+    }
+
+    /// The descendants of `root` matching the selector, in depth-first order.
+    pub fn select<'a>(&self, file: &File, root: &'a Node) -> Vec<&'a Node> {
+        let mut found = vec![];
+        let mut ancestors = vec![root];
+        self.select_in(file, root, &mut ancestors, &mut found);
+        found
+    }
+    fn select_in<'a>(
+        &self,
+        file: &File,
+        node: &'a Node,
+        ancestors: &mut Vec<&'a Node>,
+        found: &mut Vec<&'a Node>,
+    ) {
+        for child in node.children.iter().flatten() {
+            if self.matches(file, child, ancestors) {
+                found.push(child);
+            }
+            ancestors.push(child);
+            self.select_in(file, child, ancestors, found);
+            ancestors.pop();
+        }
+    }
+    /// Whether `node`, with `ancestors` from the root down, matches.
+    pub fn matches(&self, file: &File, node: &Node, ancestors: &[&Node]) -> bool {
+        let last = self.compounds.len() - 1;
+        self.compounds[last].matches(file, node) && self.matches_ancestors(file, last, ancestors)
+    }
+    /// Whether the compounds before `index` match `ancestors`.
+    fn matches_ancestors(&self, file: &File, index: usize, ancestors: &[&Node]) -> bool {
+        if index == 0 {
+            return true;
+        }
+        let compound = &self.compounds[index - 1];
+        match self.combinators[index - 1] {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, rest)) => {
+                    compound.matches(file, parent) && self.matches_ancestors(file, index - 1, rest)
+                },
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|i| {
+                compound.matches(file, ancestors[i])
+                    && self.matches_ancestors(file, index - 1, &ancestors[..i])
+            }),
+        }
+    }
+}
+impl Compound {
+    fn matches(&self, file: &File, node: &Node) -> bool {
+        self.node_type
+            .as_ref()
+            .is_none_or(|node_type| node.r#type == *node_type)
+            && self
+                .attributes
+                .iter()
+                .all(|attribute| attribute.matches(file, node))
+    }
+}
+impl Attribute {
+    fn matches(&self, file: &File, node: &Node) -> bool {
+        let node_type;
+        let actual = match self.name {
+            AttributeName::Name => Some(node.name.as_str()),
+            AttributeName::Id => Some(node.id.as_str()),
+            AttributeName::Type => {
+                node_type = type_name(&node.r#type);
+                Some(node_type.as_str())
+            },
+            AttributeName::ComponentSet => component_set_name(file, node),
+        };
+        let Some(actual) = actual else {
+            return false;
+        };
+        match self.operator {
+            Operator::Equals => actual == self.value,
+            Operator::Prefix => actual.starts_with(&self.value),
+            Operator::Contains => actual.contains(&self.value),
+            Operator::Glob => glob_match(&self.value, actual),
+        }
+    }
+}
+
+fn type_name(node_type: &NodeType) -> String {
+    serde_json::to_value(node_type)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+/// A known node type; `Other` would accept any misspelled name.
+fn parse_type(name: &str) -> Option<NodeType> {
+    serde_json::from_value(serde_json::Value::String(name.to_string()))
+        .ok()
+        .filter(|node_type| !matches!(node_type, NodeType::Other(_)))
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    fn error(&self, message: &str) -> String {
+        format!("{} at {}", message, self.pos)
+    }
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+    /// Reads the characters while `accept` holds.
+    fn take_while(&mut self, accept: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&accept) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+    /// A node type or attribute name. It stops at operator characters, so
+    /// that `name*=` reads `name`.
+    fn word(&mut self) -> String {
+        self.take_while(|c| c.is_alphanumeric() || c == '_')
+    }
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("expected `{}`", expected)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+    fn compound(&mut self) -> Result<Compound, String> {
+        let word = if self.peek() == Some('*') {
+            self.pos += 1;
+            "*".to_string()
+        } else {
+            self.word()
+        };
+        let node_type = match word.as_str() {
+            "" | "*" => None,
+            name => Some(parse_type(name).ok_or_else(|| self.error("unknown node type"))?),
+        };
+        let mut attributes = vec![];
+        while self.peek() == Some('[') {
+            self.pos += 1;
+            attributes.push(self.attribute()?);
+        }
+        if word.is_empty() && attributes.is_empty() {
+            return Err(self.error("expected a node type or an attribute"));
+        }
+        Ok(Compound { node_type, attributes })
+    }
+    fn attribute(&mut self) -> Result<Attribute, String> {
+        self.skip_whitespace();
+        let name = match self.word().as_str() {
+            "" => return Err(self.error("expected an attribute name")),
+            "name" => AttributeName::Name,
+            "id" => AttributeName::Id,
+            "type" => AttributeName::Type,
+            "componentSet" => AttributeName::ComponentSet,
+            name => return Err(self.error(&format!("unknown attribute `{}`", name))),
+        };
+        self.skip_whitespace();
+        let operator = match self.peek() {
+            Some('=') => Operator::Equals,
+            Some('^') => Operator::Prefix,
+            Some('*') => Operator::Contains,
+            Some('~') => Operator::Glob,
+            _ => return Err(self.error("expected an operator")),
+        };
+        if operator != Operator::Equals {
+            self.pos += 1;
+        }
+        self.expect('=')?;
+        self.skip_whitespace();
+        let quote = self.peek().filter(|c| *c == '"' || *c == '\'');
+        let value: String = match quote {
+            Some(quote) => {
+                self.pos += 1;
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != quote) {
+                    self.pos += 1;
+                }
+                let value = self.chars[start..self.pos].iter().collect();
+                self.expect(quote)?;
+                value
+            },
+            // unquoted values may hold glob wildcards
+            None => self.take_while(|c| !c.is_whitespace() && c != ']'),
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Attribute { name, operator, value })
+    }
+}
@@ -0,0 +1,404 @@
+use crate::{
+    gen::{diagnostic::Diagnostic, jsx::PropValue, tokens::TokenResolver},
+    schema::{
+        BlendMode, Color, Effect, EffectType, LayoutMode, Node, Paint, PaintType, ScaleMode,
+        StrokeAlign, TextCase, TextDecoration, TypeStyle, Vector,
+    },
+};
+
+/// The `sx` prop of a generated element. Keys keep their insertion order and
+/// values are JavaScript literals.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sx {
+    entries: Vec<(String, String)>,
+}
+impl Sx {
+    pub fn new() -> Self {
+        Sx { entries: vec![] }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+    /// Sets `key` to a string value, e.g. a token name or a CSS value.
+    pub fn string(&mut self, key: &str, value: &str) {
+        let value = format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"));
+        self.entries.push((key.to_string(), value));
+    }
+    pub fn number(&mut self, key: &str, value: f64) {
+        self.entries.push((key.to_string(), value.to_string()));
+    }
+    /// The `sx={{ ... }}` prop value, or `None` when nothing is set.
+    pub fn to_prop(&self) -> Option<PropValue> {
+        if self.is_empty() {
+            return None;
+        }
+        let entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect();
+        Some(PropValue::Expression(format!("{{ {} }}", entries.join(", "))))
+    }
+}
+
+pub fn px(value: f64) -> String {
+    format!("{}px", value)
+}
+pub fn is_shadow(effect: &Effect) -> bool {
+    matches!(effect.r#type, EffectType::DropShadow | EffectType::InnerShadow)
+}
+/// The CSS `box-shadow` of the shadows among `effects`, none without shadows.
+pub fn box_shadow<'a>(effects: impl IntoIterator<Item = &'a Effect>) -> Option<String> {
+    let shadows: Vec<String> = effects
+        .into_iter()
+        .filter(|e| is_shadow(e))
+        .map(|e| {
+            format!(
+                "{}{} {} {} {} {}",
+                if e.r#type == EffectType::InnerShadow { "inset " } else { "" },
+                px(e.offset.x),
+                px(e.offset.y),
+                px(e.radius),
+                px(e.spread),
+                e.color.map(|c| c.to_hex()).unwrap_or_default()
+            )
+        })
+        .collect();
+    (!shadows.is_empty()).then(|| shadows.join(", "))
+}
+fn color_value(paint: &Paint, color: Color, tokens: &TokenResolver) -> String {
+    paint
+        .bound_variables
+        .as_ref()
+        .and_then(|variables| variables.get("color"))
+        .and_then(|variable| variable.first())
+        .and_then(|alias| tokens.variable_token(&alias.id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Color { a: color.a * paint.opacity, ..color }.to_hex())
+}
+fn gradient_stops(paint: &Paint) -> String {
+    let stops: Vec<String> = paint
+        .gradient_stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            let color = Color { a: stop.color.a * paint.opacity, ..stop.color };
+            format!("{} {}%", color.to_hex(), (stop.position * 100.0).round())
+        })
+        .collect();
+    stops.join(", ")
+}
+/// The CSS angle of a linear gradient going from the first to the second
+/// handle. CSS angles start at the top and turn clockwise.
+fn gradient_angle(handles: &[Vector]) -> f64 {
+    match handles {
+        [start, end, ..] => {
+            let angle = (end.x - start.x).atan2(start.y - end.y).to_degrees();
+            ((angle + 360.0) % 360.0).round()
+        },
+        _ => 180.0,
+    }
+}
+/// The URL emitted for the image fill `image_ref`. The API serves image fills
+/// from short-lived URLs only, so generated code points at `images/<imageRef>`,
+/// where the image is expected to be exported.
+pub fn image_url(image_ref: &str) -> String {
+    format!("images/{}", image_ref)
+}
+/// The image fills of `node` that generated code refers to by `image_url`.
+pub fn image_fill_diagnostics(node: &Node) -> Vec<Diagnostic> {
+    node.fills
+        .iter()
+        .flatten()
+        .filter(|paint| paint.visible && paint.r#type == PaintType::Image)
+        .filter_map(|paint| paint.image_ref.as_deref())
+        .map(|image_ref| {
+            Diagnostic::warning(
+                node,
+                format!("image fill is referenced as `{}`, export it there", image_url(image_ref)),
+            )
+        })
+        .collect()
+}
+/// A paint as a CSS background layer.
+fn background_layer(paint: &Paint, tokens: &TokenResolver) -> Option<String> {
+    let handles = paint
+        .gradient_handle_positions
+        .as_deref()
+        .unwrap_or_default();
+    match paint.r#type {
+        PaintType::Solid => {
+            let color = color_value(paint, paint.color?, tokens);
+            Some(format!("linear-gradient({}, {})", color, color))
+        },
+        PaintType::GradientLinear => Some(format!(
+            "linear-gradient({}deg, {})",
+            gradient_angle(handles),
+            gradient_stops(paint)
+        )),
+        PaintType::GradientRadial | PaintType::GradientDiamond => {
+            Some(format!("radial-gradient({})", gradient_stops(paint)))
+        },
+        PaintType::GradientAngular => Some(format!(
+            "conic-gradient(from {}deg, {})",
+            gradient_angle(handles),
+            gradient_stops(paint)
+        )),
+        PaintType::Image => {
+            let size = match paint.scale_mode {
+                Some(ScaleMode::Fit) => "contain",
+                Some(ScaleMode::Tile) => "auto",
+                Some(ScaleMode::Stretch) => "100% 100%",
+                _ => "cover",
+            };
+            let image = image_url(paint.image_ref.as_deref()?);
+            Some(format!("url(\"{}\") center / {}", image, size))
+        },
+        PaintType::Emoji | PaintType::Video | PaintType::Other => None,
+    }
+}
+
+fn background(node: &Node, tokens: &TokenResolver, sx: &mut Sx) {
+    let paints: Vec<&Paint> = node
+        .fills
+        .iter()
+        .flatten()
+        .filter(|paint| paint.visible && paint.r#type != PaintType::Other)
+        .collect();
+    match paints.as_slice() {
+        [] => {},
+        [paint] if paint.r#type == PaintType::Solid => {
+            if let Some(color) = paint.color {
+                sx.string("backgroundColor", &color_value(paint, color, tokens));
+            }
+        },
+        paints => {
+            // CSS lists the top-most layer first, Figma the bottom-most.
+            let layers: Vec<String> = paints
+                .iter()
+                .rev()
+                .filter_map(|paint| background_layer(paint, tokens))
+                .collect();
+            if !layers.is_empty() {
+                sx.string("background", &layers.join(", "));
+            }
+        },
+    }
+}
+fn border(node: &Node, tokens: &TokenResolver, sx: &mut Sx) {
+    let Some(stroke) = node.stroke() else {
+        return;
+    };
+    let Some((paint, color)) = stroke
+        .paints
+        .iter()
+        .filter(|p| p.visible && p.r#type == PaintType::Solid)
+        .find_map(|p| p.color.map(|c| (p, c)))
+    else {
+        return;
+    };
+    let color = color_value(paint, color, tokens);
+    let line = if stroke.dashes.is_empty() { "solid" } else { "dashed" };
+    let border = |weight: f64| format!("{} {} {}", px(weight), line, color);
+    match (stroke.align, stroke.individual_weights) {
+        (StrokeAlign::Outside, _) => sx.string("outline", &border(stroke.weight)),
+        (_, Some(weights)) => {
+            for (key, weight) in [
+                ("borderTop", weights.top),
+                ("borderRight", weights.right),
+                ("borderBottom", weights.bottom),
+                ("borderLeft", weights.left),
+            ] {
+                if weight > 0.0 {
+                    sx.string(key, &border(weight));
+                }
+            }
+        },
+        _ => sx.string("border", &border(stroke.weight)),
+    }
+}
+fn border_radius(node: &Node, tokens: &TokenResolver, sx: &mut Sx) {
+    let corners = ["topLeftRadius", "topRightRadius", "bottomRightRadius", "bottomLeftRadius"];
+    let corner_tokens: Vec<Option<&str>> = corners
+        .iter()
+        .map(|corner| tokens.bound_token(node, corner))
+        .collect();
+    if let Some(token) = corner_tokens[0] {
+        if corner_tokens.iter().all(|t| *t == Some(token)) {
+            sx.string("borderRadius", token);
+            return;
+        }
+    }
+    match (node.corner_radius, node.rectangle_corner_radii) {
+        (_, Some(radii)) if radii.iter().any(|r| *r != radii[0]) => {
+            let radii: Vec<String> = radii.iter().map(|r| px(*r)).collect();
+            sx.string("borderRadius", &radii.join(" "));
+        },
+        (Some(radius), _) if radius > 0.0 => sx.string("borderRadius", &px(radius)),
+        _ => {},
+    }
+}
+fn effects(node: &Node, tokens: &TokenResolver, sx: &mut Sx) {
+    let effects: Vec<_> = node
+        .effects
+        .iter()
+        .flatten()
+        .filter(|e| e.visible)
+        .collect();
+    if let Some(token) = tokens.style_token(node, "effect") {
+        sx.string("boxShadow", token);
+    } else if let Some(shadow) = box_shadow(effects.iter().copied()) {
+        sx.string("boxShadow", &shadow);
+    }
+    // Figma blur radii are twice the CSS standard deviation.
+    for effect in effects {
+        match effect.r#type {
+            EffectType::LayerBlur => {
+                sx.string("filter", &format!("blur({})", px(effect.radius / 2.0)))
+            },
+            EffectType::BackgroundBlur => {
+                sx.string("backdropFilter", &format!("blur({})", px(effect.radius / 2.0)))
+            },
+            _ => {},
+        }
+    }
+}
+fn compositing(node: &Node, sx: &mut Sx) {
+    if let Some(opacity) = node.opacity.filter(|opacity| *opacity < 1.0) {
+        sx.number("opacity", (opacity * 100.0).round() / 100.0);
+    }
+    let blend_mode = match node.blend_mode {
+        Some(BlendMode::Darken) => "darken",
+        Some(BlendMode::Multiply) => "multiply",
+        Some(BlendMode::ColorBurn | BlendMode::LinearBurn) => "color-burn",
+        Some(BlendMode::Lighten) => "lighten",
+        Some(BlendMode::Screen) => "screen",
+        Some(BlendMode::ColorDodge | BlendMode::LinearDodge) => "color-dodge",
+        Some(BlendMode::Overlay) => "overlay",
+        Some(BlendMode::SoftLight) => "soft-light",
+        Some(BlendMode::HardLight) => "hard-light",
+        Some(BlendMode::Difference) => "difference",
+        Some(BlendMode::Exclusion) => "exclusion",
+        Some(BlendMode::Hue) => "hue",
+        Some(BlendMode::Saturation) => "saturation",
+        Some(BlendMode::Color) => "color",
+        Some(BlendMode::Luminosity) => "luminosity",
+        Some(BlendMode::PassThrough | BlendMode::Normal | BlendMode::Other) | None => return,
+    };
+    sx.string("mixBlendMode", blend_mode);
+}
+fn spacing(node: &Node, tokens: &TokenResolver, field: &str, value: Option<f64>) -> Option<String> {
+    match (tokens.bound_token(node, field), value) {
+        (Some(token), _) => Some(token.to_string()),
+        (None, Some(value)) if value > 0.0 => Some(px(value)),
+        _ => None,
+    }
+}
+fn layout(node: &Node, tokens: &TokenResolver, sx: &mut Sx) {
+    match node.layout_mode {
+        Some(LayoutMode::Horizontal) => {
+            sx.string("display", "flex");
+        },
+        Some(LayoutMode::Vertical) => {
+            sx.string("display", "flex");
+            sx.string("flexDirection", "column");
+        },
+        _ => {},
+    }
+    if let Some(gap) = spacing(node, tokens, "itemSpacing", node.item_spacing) {
+        if matches!(
+            node.layout_mode,
+            Some(LayoutMode::Horizontal | LayoutMode::Vertical | LayoutMode::Grid)
+        ) {
+            sx.string("gap", &gap);
+        }
+    }
+    let paddings = [
+        ("pt", spacing(node, tokens, "paddingTop", node.padding_top)),
+        ("pr", spacing(node, tokens, "paddingRight", node.padding_right)),
+        ("pb", spacing(node, tokens, "paddingBottom", node.padding_bottom)),
+        ("pl", spacing(node, tokens, "paddingLeft", node.padding_left)),
+    ];
+    if paddings[0].1.is_some() && paddings.iter().all(|(_, p)| *p == paddings[0].1) {
+        sx.string("p", paddings[0].1.as_deref().unwrap_or_default());
+        return;
+    }
+    for (key, padding) in paddings {
+        if let Some(padding) = padding {
+            sx.string(key, &padding);
+        }
+    }
+}
+
+/// The styling of a custom (non-library) container as `sx` props: layout,
+/// background, border, border radius, shadows and compositing. Values bound
+/// to variables or styles are emitted as Sprout tokens.
+pub fn node_sx(node: &Node, tokens: &TokenResolver) -> Sx {
+    let mut sx = Sx::new();
+    layout(node, tokens, &mut sx);
+    background(node, tokens, &mut sx);
+    border(node, tokens, &mut sx);
+    border_radius(node, tokens, &mut sx);
+    effects(node, tokens, &mut sx);
+    compositing(node, &mut sx);
+    sx
+}
+
+/// The color of the top-most visible solid paint, as a token when it is bound
+/// to a variable.
+pub fn paint_color(paints: &[Paint], tokens: &TokenResolver) -> Option<String> {
+    paints
+        .iter()
+        .rev()
+        .filter(|paint| paint.visible && paint.r#type == PaintType::Solid)
+        .find_map(|paint| paint.color.map(|color| color_value(paint, color, tokens)))
+}
+
+/// The font attributes set in `style` as `sx` props. Used for text without a
+/// Sprout typography variant and for the overridden ranges of rich text.
+pub fn typography_sx(style: &TypeStyle, tokens: &TokenResolver) -> Sx {
+    let mut sx = Sx::new();
+    if let Some(size) = style.font_size {
+        sx.string("fontSize", &px(size));
+    }
+    if let Some(weight) = style.font_weight {
+        sx.number("fontWeight", weight);
+    }
+    if let Some(line_height) = style.line_height_px {
+        sx.string("lineHeight", &px(line_height));
+    }
+    if let Some(letter_spacing) = style.letter_spacing.filter(|spacing| *spacing != 0.0) {
+        sx.string("letterSpacing", &px(letter_spacing));
+    }
+    if style.italic == Some(true) {
+        sx.string("fontStyle", "italic");
+    }
+    match style.text_decoration {
+        Some(TextDecoration::Underline) => sx.string("textDecoration", "underline"),
+        Some(TextDecoration::Strikethrough) => sx.string("textDecoration", "line-through"),
+        _ => {},
+    }
+    match style.text_case {
+        Some(TextCase::Upper) => sx.string("textTransform", "uppercase"),
+        Some(TextCase::Lower) => sx.string("textTransform", "lowercase"),
+        Some(TextCase::Title) => sx.string("textTransform", "capitalize"),
+        Some(TextCase::SmallCaps | TextCase::SmallCapsForced) => {
+            sx.string("fontVariant", "small-caps")
+        },
+        _ => {},
+    }
+    if let Some(color) = style
+        .fills
+        .as_deref()
+        .and_then(|fills| paint_color(fills, tokens))
+    {
+        sx.string("color", &color);
+    }
+    sx
+}
@@ -0,0 +1,271 @@
+use crate::{
+    gen::{
+        style::{box_shadow, is_shadow, px},
+        tokens::token_name,
+        variables::VariableResolver,
+    },
+    schema::{
+        Color, Effect, EffectType, File, Node, StyleType, TypeStyle, VariableResolvedType,
+        VariableValue,
+    },
+};
+use serde_json::{json, Map, Value};
+use std::{collections::HashMap, fmt::Write};
+
+/// The name of a token within the group it shares its name with.
+pub const DEFAULT_TOKEN: &str = "DEFAULT";
+
+#[derive(Debug, Clone)]
+pub enum TokenValue {
+    Color(Color),
+    Dimension(f64),
+    Number(f64),
+    String(String),
+    Typography(TypeStyle),
+    Shadow(Vec<Effect>),
+}
+/// A design token read from a Figma style or variable.
+#[derive(Debug, Clone)]
+pub struct DesignToken {
+    /// The Sprout token name, e.g. `text.primary`.
+    pub name: String,
+    pub description: String,
+    pub value: TokenValue,
+}
+
+/// Reads the value of every style of `file` from the first node using it.
+/// Styles that are not applied to any node of the document, and GRID styles,
+/// have no value in the file and are returned by name in the second list.
+pub fn collect_style_tokens(file: &File) -> (Vec<DesignToken>, Vec<String>) {
+    let mut nodes = HashMap::new();
+    collect_style_nodes(&file.document, &mut nodes);
+
+    let mut tokens = vec![];
+    let mut skipped = vec![];
+    let mut styles: Vec<_> = file.styles.iter().collect();
+    styles.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+    for (id, style) in styles {
+        let value = nodes
+            .get(id.as_str())
+            .and_then(|node| match style.style_type {
+                StyleType::Fill => node
+                    .fills
+                    .iter()
+                    .flatten()
+                    .filter(|paint| paint.visible)
+                    .find_map(|paint| paint.solid_color())
+                    .map(TokenValue::Color),
+                StyleType::Text => node.style.clone().map(TokenValue::Typography),
+                StyleType::Effect => {
+                    let shadows: Vec<Effect> = node
+                        .effects
+                        .iter()
+                        .flatten()
+                        .filter(|e| e.visible && is_shadow(e))
+                        .cloned()
+                        .collect();
+                    (!shadows.is_empty()).then_some(TokenValue::Shadow(shadows))
+                },
+                StyleType::Grid => None,
+            });
+        match value {
+            Some(value) => tokens.push(DesignToken {
+                name: token_name(&style.name),
+                description: style.description.clone(),
+                value,
+            }),
+            None => skipped.push(style.name.clone()),
+        }
+    }
+    (tokens, skipped)
+}
+fn collect_style_nodes<'a>(node: &'a Node, nodes: &mut HashMap<&'a str, &'a Node>) {
+    if let Some(styles) = &node.styles {
+        for id in styles.values() {
+            nodes.entry(id.as_str()).or_insert(node);
+        }
+    }
+    for child in node.children.iter().flatten() {
+        collect_style_nodes(child, nodes);
+    }
+}
+
+/// Reads every variable in the default mode of its collection.
+pub fn collect_variable_tokens(variables: &VariableResolver) -> Vec<DesignToken> {
+    let mut tokens: Vec<DesignToken> = variables
+        .variables()
+        .filter_map(|variable| {
+            let value = match (variable.resolved_type, variables.resolve(&variable.id, None)?) {
+                (_, VariableValue::Color(color)) => TokenValue::Color(*color),
+                (VariableResolvedType::Float, VariableValue::Float(value)) => {
+                    TokenValue::Dimension(*value)
+                },
+                (_, VariableValue::Float(value)) => TokenValue::Number(*value),
+                (_, VariableValue::String(value)) => TokenValue::String(value.clone()),
+                (_, VariableValue::Boolean(value)) => TokenValue::String(value.to_string()),
+                (_, VariableValue::Alias(_)) => return None,
+            };
+            Some(DesignToken {
+                name: token_name(&variable.name),
+                description: variable.description.clone(),
+                value,
+            })
+        })
+        .collect();
+    tokens.sort_by(|a, b| a.name.cmp(&b.name));
+    tokens
+}
+
+/// Whole numbers are written without a fraction, e.g. font weights.
+fn number(value: f64) -> Value {
+    if value.fract() == 0.0 {
+        json!(value as i64)
+    } else {
+        json!(value)
+    }
+}
+fn shadow_value(effect: &Effect) -> Value {
+    let mut shadow = json!({
+        "color": effect.color.map(|c| c.to_hex()).unwrap_or_default(),
+        "offsetX": px(effect.offset.x),
+        "offsetY": px(effect.offset.y),
+        "blur": px(effect.radius),
+        "spread": px(effect.spread),
+    });
+    if effect.r#type == EffectType::InnerShadow {
+        shadow["inset"] = json!(true);
+    }
+    shadow
+}
+fn typography_value(style: &TypeStyle) -> Value {
+    let mut value = Map::new();
+    if let Some(family) = &style.font_family {
+        value.insert("fontFamily".to_string(), json!(family));
+    }
+    if let Some(weight) = style.font_weight {
+        value.insert("fontWeight".to_string(), number(weight));
+    }
+    if let Some(size) = style.font_size {
+        value.insert("fontSize".to_string(), json!(px(size)));
+    }
+    if let Some(line_height) = style.line_height_px {
+        value.insert("lineHeight".to_string(), json!(px(line_height)));
+    }
+    if let Some(letter_spacing) = style.letter_spacing {
+        value.insert("letterSpacing".to_string(), json!(px(letter_spacing)));
+    }
+    Value::Object(value)
+}
+fn dtcg_token(token: &DesignToken) -> Value {
+    let (token_type, value) = match &token.value {
+        TokenValue::Color(color) => ("color", json!(color.to_hex())),
+        TokenValue::Dimension(value) => ("dimension", json!(px(*value))),
+        TokenValue::Number(value) => ("number", number(*value)),
+        TokenValue::String(value) => ("string", json!(value)),
+        TokenValue::Typography(style) => ("typography", typography_value(style)),
+        TokenValue::Shadow(effects) => {
+            ("shadow", Value::Array(effects.iter().map(shadow_value).collect()))
+        },
+    };
+    let mut result = json!({ "$type": token_type, "$value": value });
+    if !token.description.is_empty() {
+        result["$description"] = json!(token.description);
+    }
+    result
+}
+
+/// Writes the tokens as a [Design Tokens Community Group](https://tr.designtokens.org/format/)
+/// JSON file, grouping tokens by the segments of their names. A token named
+/// like a group, e.g. `text` next to `text.primary`, is written as the
+/// group's `DEFAULT` token, since tokens cannot contain other tokens.
+pub fn to_design_tokens_json(tokens: &[DesignToken]) -> String {
+    let mut root = Map::new();
+    for token in tokens {
+        let Value::Object(fields) = dtcg_token(token) else {
+            continue;
+        };
+        let mut group = &mut root;
+        for segment in token.name.split('.') {
+            let child = group
+                .entry(segment.to_string())
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .expect("groups and tokens are objects");
+            if is_dtcg_token(child) {
+                // a token where a group goes moves into the group
+                let token = std::mem::take(child);
+                child.insert(DEFAULT_TOKEN.to_string(), Value::Object(token));
+            }
+            group = child;
+        }
+        if group.is_empty() {
+            *group = fields;
+        } else {
+            group.insert(DEFAULT_TOKEN.to_string(), Value::Object(fields));
+        }
+    }
+    serde_json::to_string_pretty(&Value::Object(root)).unwrap_or_default()
+}
+fn is_dtcg_token(fields: &Map<String, Value>) -> bool {
+    fields.contains_key("$value")
+}
+
+/// `value` as a double quoted CSS string. Quotes and backslashes are escaped,
+/// line breaks and other control characters are written as hex escapes.
+fn css_string(value: &str) -> String {
+    let mut css = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                css.push('\\');
+                css.push(c);
+            },
+            c if c.is_control() => {
+                let _ = write!(css, "\\{:x} ", c as u32);
+            },
+            c => css.push(c),
+        }
+    }
+    css.push('"');
+    css
+}
+
+/// Writes the tokens as CSS custom properties on `:root`. Typography tokens
+/// become one property per font attribute.
+pub fn to_css_variables(tokens: &[DesignToken]) -> String {
+    let mut css = String::from(":root {\n");
+    for token in tokens {
+        let name = format!("--{}", token.name.replace('.', "-"));
+        let mut declare = |suffix: &str, value: String| {
+            let _ = writeln!(css, "  {}{}: {};", name, suffix, value);
+        };
+        match &token.value {
+            TokenValue::Color(color) => declare("", color.to_hex()),
+            TokenValue::Dimension(value) => declare("", px(*value)),
+            TokenValue::Number(value) => declare("", value.to_string()),
+            TokenValue::String(value) => declare("", css_string(value)),
+            TokenValue::Typography(style) => {
+                if let Some(family) = &style.font_family {
+                    declare("-font-family", css_string(family));
+                }
+                if let Some(weight) = style.font_weight {
+                    declare("-font-weight", weight.to_string());
+                }
+                if let Some(size) = style.font_size {
+                    declare("-font-size", px(size));
+                }
+                if let Some(line_height) = style.line_height_px {
+                    declare("-line-height", px(line_height));
+                }
+                if let Some(letter_spacing) = style.letter_spacing {
+                    declare("-letter-spacing", px(letter_spacing));
+                }
+            },
+            TokenValue::Shadow(effects) => {
+                declare("", box_shadow(effects).unwrap_or_default());
+            },
+        }
+    }
+    css.push_str("}\n");
+    css
+}
@@ -0,0 +1,104 @@
+use crate::{
+    gen::variables::{variable_key, VariableResolver},
+    schema::{File, Node, PublishedVariables, StyleType},
+};
+use std::collections::HashMap;
+
+/// Leading path segments of Figma style and variable names that are not part
+/// of the Sprout token name.
+const IGNORED_PREFIXES: [&str; 3] = ["sprout", "light", "dark"];
+
+/// Converts a Figma style or variable name into a Sprout theme token name,
+/// e.g. `Light/Text/Primary` into `text.primary` and `sprout/elevation/strong`
+/// into `elevation.strong`.
+pub fn token_name(figma_name: &str) -> String {
+    let segments: Vec<String> = figma_name
+        .split('/')
+        .map(|segment| segment.trim().to_lowercase().replace(' ', "-"))
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let skip = segments
+        .iter()
+        .take_while(|segment| IGNORED_PREFIXES.contains(&segment.as_str()))
+        .count();
+    segments[skip..].join(".")
+}
+
+/// Maps the styles and variables referenced by nodes to Sprout theme tokens,
+/// so that generated code can use `color="text.primary"` instead of raw values.
+#[derive(Debug, Default)]
+pub struct TokenResolver {
+    styles: HashMap<String, (StyleType, String)>,
+    variables: HashMap<String, String>,
+}
+impl TokenResolver {
+    pub fn new() -> Self {
+        TokenResolver { styles: HashMap::new(), variables: HashMap::new() }
+    }
+    pub fn from_file(file: &File) -> Self {
+        let mut resolver = Self::new();
+        for (id, style) in &file.styles {
+            resolver
+                .styles
+                .insert(id.clone(), (style.style_type, token_name(&style.name)));
+        }
+        resolver
+    }
+    /// Registers the name of a variable. `id` is either the variable id or the
+    /// variable key, which matches the aliases of a library variable.
+    pub fn add_variable(&mut self, id: &str, name: &str) {
+        self.variables.insert(id.to_string(), token_name(name));
+    }
+    /// Registers the local variables of a file, and of a library file whose
+    /// variables are referenced by key.
+    pub fn add_local_variables(&mut self, variables: &VariableResolver) {
+        for variable in variables.variables() {
+            self.add_variable(&variable.id, &variable.name);
+            self.add_variable(&variable.key, &variable.name);
+        }
+    }
+    pub fn add_published_variables(&mut self, variables: &PublishedVariables) {
+        for variable in variables.variables.values() {
+            self.add_variable(&variable.key, &variable.name);
+            self.add_variable(&variable.subscribed_id, &variable.name);
+        }
+    }
+    pub fn has_variables(&self) -> bool {
+        !self.variables.is_empty()
+    }
+
+    /// The token of the style `style_key` (`"fill"`, `"text"`, `"effect"`...)
+    /// applied to `node`.
+    pub fn style_token(&self, node: &Node, style_key: &str) -> Option<&str> {
+        let style_id = node.styles.as_ref()?.get(style_key)?;
+        self.styles.get(style_id).map(|(_, token)| token.as_str())
+    }
+    /// The token of the style of `style_type` applied to `node`.
+    pub fn style_token_of_type(&self, node: &Node, style_type: StyleType) -> Option<&str> {
+        node.styles
+            .as_ref()?
+            .values()
+            .find_map(|id| match self.styles.get(id) {
+                Some((t, token)) if *t == style_type => Some(token.as_str()),
+                _ => None,
+            })
+    }
+    /// The token of the variable with the alias id `id`.
+    pub fn variable_token(&self, id: &str) -> Option<&str> {
+        self.variables
+            .get(id)
+            .or_else(|| variable_key(id).and_then(|key| self.variables.get(key)))
+            .map(String::as_str)
+    }
+    /// The token of the variable bound to `field` of `node`, e.g. `paddingLeft`.
+    pub fn bound_token(&self, node: &Node, field: &str) -> Option<&str> {
+        let alias = node.bound_variables.as_ref()?.get(field)?.first()?;
+        self.variable_token(&alias.id)
+    }
+    /// The token of the fill of `node`: a bound color variable, or else the
+    /// fill style.
+    pub fn fill_token(&self, node: &Node) -> Option<&str> {
+        self.bound_token(node, "fills")
+            .or_else(|| self.style_token(node, "fill"))
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    gen::{diagnostic::Diagnostic, node_util::is_property_name, query::descendants},
+    schema::{ComponentPropertyDefinition, ComponentPropertyType, File, Node, NodeType},
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+type Definitions = HashMap<String, ComponentPropertyDefinition>;
+
+/// The `componentPropertyDefinitions` of the component sets known to the
+/// generator, keyed by component (set) key so that definitions read from a
+/// library file apply to the remote instances of another file.
+#[derive(Debug, Default)]
+pub struct PropertyDefinitions {
+    definitions: HashMap<String, Definitions>,
+}
+impl PropertyDefinitions {
+    pub fn new() -> Self {
+        PropertyDefinitions { definitions: HashMap::new() }
+    }
+    pub fn from_file(file: &File) -> Self {
+        let mut definitions = Self::new();
+        definitions.add_file(file);
+        definitions
+    }
+    /// Collects the definitions of every COMPONENT_SET and standalone COMPONENT
+    /// in `file`, e.g. a library file.
+    pub fn add_file(&mut self, file: &File) {
+        self.collect(file, &file.document);
+    }
+    fn collect(&mut self, file: &File, node: &Node) {
+        if let Some(definitions) = &node.component_property_definitions {
+            let key = match node.r#type {
+                NodeType::ComponentSet => file.component_sets.get(&node.id).map(|s| &s.key),
+                _ => file.components.get(&node.id).map(|c| &c.key),
+            };
+            let key = key.unwrap_or(&node.id).clone();
+            self.definitions.insert(key, definitions.clone());
+        }
+        if let Some(children) = &node.children {
+            for child in children {
+                self.collect(file, child);
+            }
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+    /// The definitions of the component set the INSTANCE `node` belongs to.
+    pub fn for_instance(&self, file: &File, node: &Node) -> Option<&Definitions> {
+        let component_id = node.component_id.as_ref()?;
+        let Some(component) = file.components.get(component_id) else {
+            return self.definitions.get(component_id);
+        };
+        match &component.component_set_id {
+            Some(set_id) => {
+                let key = file
+                    .component_sets
+                    .get(set_id)
+                    .map(|s| &s.key)
+                    .unwrap_or(set_id);
+                self.definitions.get(key)
+            },
+            None => self.definitions.get(&component.key),
+        }
+    }
+    pub fn find(
+        &self,
+        file: &File,
+        node: &Node,
+        name: &str,
+    ) -> Option<&ComponentPropertyDefinition> {
+        self.for_instance(file, node)?
+            .iter()
+            .find(|(key, _)| is_property_name(key, name))
+            .map(|(_, definition)| definition)
+    }
+}
+
+/// Checks the component properties of an INSTANCE against the definitions of
+/// its component set. Instances of outdated or detached library versions show
+/// up as unknown properties or variant values.
+pub fn validate_instance(
+    definitions: &PropertyDefinitions,
+    file: &File,
+    node: &Node,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let Some(library) = definitions.for_instance(file, node) else {
+        return diagnostics;
+    };
+    let properties: BTreeMap<_, _> = node.component_properties.iter().flatten().collect();
+    for (name, property) in &properties {
+        let Some(definition) = library.get(*name) else {
+            diagnostics.push(Diagnostic::warning(
+                node,
+                format!("property `{}` is not defined by the library component", name),
+            ));
+            continue;
+        };
+        if definition.value_type != property.value_type() {
+            diagnostics.push(Diagnostic::error(
+                node,
+                format!(
+                    "property `{}` is {:?} but the library defines it as {:?}",
+                    name,
+                    property.value_type(),
+                    definition.value_type
+                ),
+            ));
+            continue;
+        }
+        if let (ComponentPropertyType::Variant, Some(options), Value::String(value)) =
+            (definition.value_type, &definition.variant_options, property.value())
+        {
+            if !options.contains(value) {
+                diagnostics.push(Diagnostic::error(
+                    node,
+                    format!(
+                        "variant `{}` has value `{}`, expected one of: {}",
+                        name,
+                        value,
+                        options.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+    let mut missing: Vec<_> = library
+        .keys()
+        .filter(|name| !properties.contains_key(name))
+        .collect();
+    missing.sort();
+    for name in missing {
+        diagnostics.push(Diagnostic::warning(
+            node,
+            format!("property `{}` of the library component is missing", name),
+        ));
+    }
+    diagnostics
+}
+
+/// A warning for every node of `file` whose type isn't known, and that is
+/// therefore skipped by the generators.
+pub fn validate_node_types(file: &File) -> Vec<Diagnostic> {
+    descendants(&file.document)
+        .filter_map(|node| match &node.r#type {
+            NodeType::Other(node_type) => {
+                Some(Diagnostic::warning(node, format!("unrecognised node type `{}`", node_type)))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Validates every INSTANCE of `file`.
+pub fn validate_file(definitions: &PropertyDefinitions, file: &File) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    validate_node(definitions, file, &file.document, &mut diagnostics);
+    diagnostics
+}
+fn validate_node(
+    definitions: &PropertyDefinitions,
+    file: &File,
+    node: &Node,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if node.r#type == NodeType::Instance {
+        diagnostics.extend(validate_instance(definitions, file, node));
+    }
+    if let Some(children) = &node.children {
+        for child in children {
+            validate_node(definitions, file, child, diagnostics);
+        }
+    }
+}
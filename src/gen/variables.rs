@@ -0,0 +1,110 @@
+use crate::schema::{
+    LocalVariables, LocalVariablesResponse, PublishedVariables, PublishedVariablesResponse,
+    Variable, VariableCollection, VariableValue,
+};
+use serde_json::from_str;
+use std::collections::{HashMap, HashSet};
+
+pub fn convert_json_to_local_variables(json: &str) -> Result<LocalVariables, String> {
+    from_str::<LocalVariablesResponse>(json)
+        .map(|response| response.meta)
+        .map_err(|e| e.to_string())
+}
+pub fn convert_json_to_published_variables(json: &str) -> Result<PublishedVariables, String> {
+    from_str::<PublishedVariablesResponse>(json)
+        .map(|response| response.meta)
+        .map_err(|e| e.to_string())
+}
+
+/// The key part of a library variable id such as
+/// `VariableID:2799168d091fc5dfc422ab70af9e2db83b4c671c/2612:91`.
+pub fn variable_key(id: &str) -> Option<&str> {
+    let id = id.strip_prefix("VariableID:")?;
+    id.split_once('/').map(|(key, _)| key)
+}
+
+/// Resolves variable values per mode, following alias chains across
+/// collections.
+#[derive(Debug, Default)]
+pub struct VariableResolver {
+    variables: HashMap<String, Variable>,
+    collections: HashMap<String, VariableCollection>,
+    /// Variable ids by key, for aliases to library variables.
+    ids_by_key: HashMap<String, String>,
+}
+impl VariableResolver {
+    pub fn new(local: LocalVariables) -> Self {
+        let ids_by_key = local
+            .variables
+            .values()
+            .map(|v| (v.key.clone(), v.id.clone()))
+            .collect();
+        VariableResolver {
+            variables: local.variables,
+            collections: local.variable_collections,
+            ids_by_key,
+        }
+    }
+    pub fn variables(&self) -> impl Iterator<Item = &Variable> {
+        self.variables.values()
+    }
+    pub fn collections(&self) -> impl Iterator<Item = &VariableCollection> {
+        self.collections.values()
+    }
+    /// Finds a variable by id, or by the key part of a library alias id such as
+    /// `VariableID:<key>/2612:91`.
+    pub fn variable(&self, id: &str) -> Option<&Variable> {
+        self.variables
+            .get(id)
+            .or_else(|| self.variables.get(self.ids_by_key.get(variable_key(id)?)?))
+    }
+    /// The id of the mode called `name` (e.g. `Dark`) in the collection of the
+    /// variable `id`.
+    pub fn mode_id(&self, id: &str, name: &str) -> Option<&str> {
+        let collection = self
+            .collections
+            .get(&self.variable(id)?.variable_collection_id)?;
+        collection
+            .modes
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| m.mode_id.as_str())
+    }
+    /// The value of the variable `id` in `mode_id`, or in the default mode of its
+    /// collection. Aliases are followed until a concrete value is found; an
+    /// alias into another collection continues with the mode of the same name,
+    /// falling back to that collection's default mode.
+    pub fn resolve(&self, id: &str, mode_id: Option<&str>) -> Option<&VariableValue> {
+        let mut visited = HashSet::new();
+        let mut variable = self.variable(id)?;
+        let mut collection = self.collections.get(&variable.variable_collection_id)?;
+        let mut mode_id = mode_id
+            .filter(|mode| collection.modes.iter().any(|m| m.mode_id == *mode))
+            .unwrap_or(&collection.default_mode_id)
+            .to_string();
+        loop {
+            if !visited.insert(variable.id.as_str()) {
+                // alias cycle
+                return None;
+            }
+            let value = variable
+                .values_by_mode
+                .get(&mode_id)
+                .or_else(|| variable.values_by_mode.get(&collection.default_mode_id))?;
+            let VariableValue::Alias(alias) = value else {
+                return Some(value);
+            };
+            let target = self.variable(&alias.id)?;
+            if target.variable_collection_id != collection.id {
+                let mode_name = collection.modes.iter().find(|m| m.mode_id == mode_id);
+                let target_collection = self.collections.get(&target.variable_collection_id)?;
+                mode_id = mode_name
+                    .and_then(|mode| target_collection.modes.iter().find(|m| m.name == mode.name))
+                    .map(|m| m.mode_id.clone())
+                    .unwrap_or_else(|| target_collection.default_mode_id.clone());
+                collection = target_collection;
+            }
+            variable = target;
+        }
+    }
+}
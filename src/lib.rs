@@ -0,0 +1,8 @@
+pub mod app;
+pub mod cache;
+pub mod figma_api;
+pub mod files;
+pub mod gen;
+pub mod schema;
+pub mod settings;
+pub mod ui;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// An RGBA color, each channel between 0 and 1.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#color-type)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    #[serde(default = "super::default_one")]
+    pub a: f64,
+}
+impl Color {
+    /// The color as `#rrggbb`, or `#rrggbbaa` when it is translucent.
+    pub fn to_hex(&self) -> String {
+        let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let hex = format!("#{:02x}{:02x}{:02x}", channel(self.r), channel(self.g), channel(self.b));
+        if self.a < 1.0 {
+            format!("{}{:02x}", hex, channel(self.a))
+        } else {
+            hex
+        }
+    }
+}
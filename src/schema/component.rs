@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+/// An entry of the file level `components` map.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#component-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentMetadata {
+    /// The key uniquely identifying this component across files.
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Whether the component comes from a team library.
+    #[serde(default)]
+    pub remote: bool,
+    /// The id of the component set this component is a variant of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_set_id: Option<String>,
+}
+/// An entry of the file level `componentSets` map.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#component-set-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentSetMetadata {
+    /// The key uniquely identifying this component set across files.
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub remote: bool,
+}
@@ -15,11 +15,21 @@ pub struct ComponentProperty {
     #[serde(rename = "type")]
     value_type: ComponentPropertyType,
 }
-impl Into<Option<String>> for &ComponentProperty {
-    fn into(self) -> Option<String> {
-        match self.value_type {
-            ComponentPropertyType::Variant | ComponentPropertyType::Text => {
-                if let Value::String(value) = &self.value {
+impl ComponentProperty {
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+    pub fn value_type(&self) -> ComponentPropertyType {
+        self.value_type
+    }
+}
+impl From<&ComponentProperty> for Option<String> {
+    fn from(property: &ComponentProperty) -> Self {
+        match property.value_type {
+            ComponentPropertyType::Variant
+            | ComponentPropertyType::Text
+            | ComponentPropertyType::InstanceSwap => {
+                if let Value::String(value) = &property.value {
                     Some(value.clone())
                 } else {
                     None
@@ -29,12 +39,12 @@ impl Into<Option<String>> for &ComponentProperty {
         }
     }
 }
-impl Into<Option<bool>> for &ComponentProperty {
-    fn into(self) -> Option<bool> {
-        match self.value_type {
+impl From<&ComponentProperty> for Option<bool> {
+    fn from(property: &ComponentProperty) -> Self {
+        match property.value_type {
             ComponentPropertyType::Boolean => {
-                if let Value::Bool(value) = &self.value {
-                    Some(value.clone())
+                if let Value::Bool(value) = &property.value {
+                    Some(*value)
                 } else {
                     None
                 }
@@ -43,3 +53,18 @@ impl Into<Option<bool>> for &ComponentProperty {
         }
     }
 }
+/// A property exposed by a component or component set, as found in
+/// `componentPropertyDefinitions`.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#component-property-definition-type)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentPropertyDefinition {
+    #[serde(rename = "type")]
+    pub value_type: ComponentPropertyType,
+    /// Initial value of this property for instances.
+    pub default_value: Value,
+    /// All possible values for this property. Only exists on VARIANT properties.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_options: Option<Vec<String>>,
+}
@@ -0,0 +1,41 @@
+use super::Color;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone, Default)]
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EffectType {
+    InnerShadow,
+    DropShadow,
+    LayerBlur,
+    BackgroundBlur,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// A shadow or blur applied to a node.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#effect-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Effect {
+    pub r#type: EffectType,
+    #[serde(default = "super::default_true")]
+    pub visible: bool,
+    /// The blur radius.
+    #[serde(default)]
+    pub radius: f64,
+    /// The color of shadows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The offset of shadows.
+    #[serde(default)]
+    pub offset: Vector,
+    /// How far shadows spread.
+    #[serde(default)]
+    pub spread: f64,
+}
@@ -1,5 +1,6 @@
-use super::Node;
+use super::{ComponentMetadata, ComponentSetMetadata, Node, Style};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -7,4 +8,50 @@ pub struct File {
     pub document: Node,
     pub name: String,
     pub version: String,
+    /// Components used in the document, keyed by node id.
+    #[serde(default)]
+    pub components: HashMap<String, ComponentMetadata>,
+    /// Component sets used in the document, keyed by node id.
+    #[serde(default)]
+    pub component_sets: HashMap<String, ComponentSetMetadata>,
+    /// Styles used in the document, keyed by style id.
+    #[serde(default)]
+    pub styles: HashMap<String, Style>,
+}
+
+/// A node requested from `GET /v1/files/:key/nodes`, with the components and
+/// styles it uses.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileNode {
+    pub document: Node,
+    #[serde(default)]
+    pub components: HashMap<String, ComponentMetadata>,
+    #[serde(default)]
+    pub component_sets: HashMap<String, ComponentSetMetadata>,
+    #[serde(default)]
+    pub styles: HashMap<String, Style>,
+}
+/// The response of `GET /v1/files/:key/nodes`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileNodesResponse {
+    pub name: String,
+    pub version: String,
+    /// The requested nodes by id, none for ids not in the file.
+    pub nodes: HashMap<String, Option<FileNode>>,
+}
+impl FileNodesResponse {
+    /// The node `id` as a file of its own, to generate code from.
+    pub fn into_file(mut self, id: &str) -> Option<File> {
+        let node = self.nodes.remove(id)??;
+        Some(File {
+            document: node.document,
+            name: self.name,
+            version: self.version,
+            components: node.components,
+            component_sets: node.component_sets,
+            styles: node.styles,
+        })
+    }
 }
@@ -0,0 +1,75 @@
+use std::{fmt, str::FromStr};
+
+const INSTANCE_PREFIX: char = 'I';
+const SEPARATOR: char = ';';
+
+/// The id of a layer inside instances, e.g. `I2:982;21392:6615;21392:5912`:
+/// the id of the outermost instance followed by the ids of the layers of the
+/// main components, one per level of nesting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstancePath {
+    segments: Vec<String>,
+}
+impl InstancePath {
+    /// The ids of the instance and of the nested layers.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+    /// Whether the id is a layer of an instance rather than a plain node id.
+    pub fn is_nested(&self) -> bool {
+        self.segments.len() > 1
+    }
+    /// The id of the outermost instance.
+    pub fn root(&self) -> &str {
+        &self.segments[0]
+    }
+    /// The path of the enclosing layer, `None` for the outermost instance.
+    pub fn parent(&self) -> Option<InstancePath> {
+        self.prefix(self.segments.len() - 1)
+    }
+    /// The path of the first `len` segments, e.g. `I2:982;21392:6615` for the
+    /// first two.
+    pub fn prefix(&self, len: usize) -> Option<InstancePath> {
+        (len > 0 && len <= self.segments.len())
+            .then(|| InstancePath { segments: self.segments[..len].to_vec() })
+    }
+    /// The paths from the outermost instance down to this layer.
+    pub fn ancestors_and_self(&self) -> impl Iterator<Item = InstancePath> + '_ {
+        (1..=self.segments.len()).filter_map(|len| self.prefix(len))
+    }
+    /// The path of the layer `id` of the main component of this instance.
+    pub fn child(&self, id: &str) -> InstancePath {
+        let mut segments = self.segments.clone();
+        segments.push(id.to_string());
+        InstancePath { segments }
+    }
+    /// Whether this path is a layer of the instance `ancestor`.
+    pub fn starts_with(&self, ancestor: &InstancePath) -> bool {
+        self.segments.starts_with(&ancestor.segments)
+    }
+}
+impl FromStr for InstancePath {
+    type Err = String;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<String> = match id.strip_prefix(INSTANCE_PREFIX) {
+            Some(path) => path.split(SEPARATOR).map(str::to_string).collect(),
+            None => vec![id.to_string()],
+        };
+        if segments
+            .iter()
+            .any(|segment| segment.is_empty() || segment.contains(SEPARATOR))
+        {
+            return Err(format!("invalid node id `{}`", id));
+        }
+        Ok(InstancePath { segments })
+    }
+}
+impl fmt::Display for InstancePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nested() {
+            write!(f, "{}", INSTANCE_PREFIX)?;
+        }
+        write!(f, "{}", self.segments.join(&SEPARATOR.to_string()))
+    }
+}
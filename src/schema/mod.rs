@@ -1,7 +1,33 @@
+mod color;
+mod component;
 mod component_property;
+mod effect;
 mod file;
+mod instance_path;
 mod node;
+mod node_id;
+mod paint;
+pub mod parser;
+mod style;
+mod type_style;
+mod variable;
 
+pub use color::Color;
+pub use component::*;
 pub use component_property::*;
-pub use file::File;
+pub use effect::*;
+pub use file::{File, FileNode, FileNodesResponse};
+pub use instance_path::InstancePath;
 pub use node::*;
+pub use node_id::{NodeId, NodePath};
+pub use paint::*;
+pub use style::*;
+pub use type_style::*;
+pub use variable::*;
+
+fn default_true() -> bool {
+    true
+}
+fn default_one() -> f64 {
+    1.0
+}
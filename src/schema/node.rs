@@ -1,8 +1,13 @@
-use crate::schema::ComponentProperty;
+use crate::schema::{
+    BlendMode, BoundVariable, ComponentProperty, ComponentPropertyDefinition, Effect, LineType,
+    Paint, Stroke, StrokeAlign, StrokeWeights, TypeStyle,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+/// The type of a node. Types this crate doesn't know are kept as `Other`, so
+/// that new Figma or FigJam node types don't fail the whole file.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NodeType {
     Document,
@@ -25,6 +30,40 @@ pub enum NodeType {
     ShapeWithText,
     Connector,
     Section,
+    Widget,
+    Embed,
+    LinkUnfurl,
+    Media,
+    Stamp,
+    Table,
+    TableCell,
+    WashiTape,
+    Highlight,
+    CodeBlock,
+    TextPath,
+    #[serde(untagged)]
+    Other(String),
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LayoutMode {
+    None,
+    Horizontal,
+    Vertical,
+    Grid,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// The fields of a layer of an instance that differ from its main component.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#overrides-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Overrides {
+    /// The id of the overridden layer, the instance itself or a descendant.
+    pub id: String,
+    pub overridden_fields: Vec<String>,
 }
 /// [Figma documentation](https://www.figma.com/developers/api#node-types)
 #[derive(Debug, Deserialize, Serialize)]
@@ -44,5 +83,113 @@ pub struct Node {
     pub children: Option<Vec<Node>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub component_properties: Option<HashMap<String, ComponentProperty>>,
+    /// The properties exposed by a COMPONENT or COMPONENT_SET, keyed by property name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_property_definitions: Option<HashMap<String, ComponentPropertyDefinition>>,
+    /// The id of the component an INSTANCE node is an instance of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_id: Option<String>,
+    /// The component properties of the enclosing instance that set this
+    /// layer's `visible`, `characters` or `mainComponent`, by field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_property_references: Option<HashMap<String, String>>,
     pub characters: Option<String>,
+    /// The typography of a TEXT node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<TypeStyle>,
+    /// The `styleOverrideTable` id of each character of a TEXT node, in UTF-16
+    /// code units. Trailing characters without override are omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character_style_overrides: Option<Vec<usize>>,
+    /// The overridden typography of character ranges, keyed by override id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style_override_table: Option<HashMap<String, TypeStyle>>,
+    /// The list type of each line of a TEXT node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_types: Option<Vec<LineType>>,
+    /// The list nesting level of each line of a TEXT node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_indentations: Option<Vec<usize>>,
+    /// The fills of the node, bottom-most first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fills: Option<Vec<Paint>>,
+    /// The shadows and blurs applied to the node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effects: Option<Vec<Effect>>,
+    /// The styles applied to the node, e.g. `"fill"` or `"text"`, mapped to
+    /// the style id in the file level `styles` map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub styles: Option<HashMap<String, String>>,
+    /// The variables bound to fields of the node, keyed by field name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bound_variables: Option<HashMap<String, BoundVariable>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strokes: Option<Vec<Paint>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stroke_weight: Option<f64>,
+    /// Per side stroke weights, when they differ.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual_stroke_weights: Option<StrokeWeights>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stroke_align: Option<StrokeAlign>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stroke_dashes: Option<Vec<f64>>,
+    /// Radius of each corner when they are all equal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corner_radius: Option<f64>,
+    /// Top left, top right, bottom right and bottom left corner radii.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rectangle_corner_radii: Option<[f64; 4]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend_mode: Option<BlendMode>,
+    /// Opacity of the node, between 0 and 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<f64>,
+    /// Whether auto layout stacks the children `HORIZONTAL`ly or `VERTICAL`ly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout_mode: Option<LayoutMode>,
+    /// The space between the children of an auto layout frame.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_spacing: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding_left: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding_right: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding_top: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding_bottom: Option<f64>,
+    /// The overrides of an INSTANCE and of its descendants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<Overrides>>,
+    /// The ids of the nested instances whose properties are exposed on this
+    /// INSTANCE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposed_instances: Option<Vec<String>>,
+    /// Whether this nested INSTANCE is exposed on its enclosing instance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_exposed_instance: Option<bool>,
+    /// The fields this crate doesn't model, kept as they are.
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+impl Node {
+    /// Whether the node is shown; nodes are visible unless `visible` is false.
+    pub fn is_visible(&self) -> bool {
+        self.visible.unwrap_or(true)
+    }
+    /// The stroke of the node, if it has visible stroke paints.
+    pub fn stroke(&self) -> Option<Stroke<'_>> {
+        let paints = self.strokes.as_deref()?;
+        if !paints.iter().any(|paint| paint.visible) {
+            return None;
+        }
+        Some(Stroke {
+            paints,
+            weight: self.stroke_weight.unwrap_or(1.0),
+            individual_weights: self.individual_stroke_weights,
+            align: self.stroke_align.unwrap_or(StrokeAlign::Inside),
+            dashes: self.stroke_dashes.as_deref().unwrap_or_default(),
+        })
+    }
 }
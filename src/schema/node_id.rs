@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// The id of a node, unique within a file, e.g. `2:982`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(String);
+impl NodeId {
+    pub fn new(id: impl Into<String>) -> Self {
+        NodeId(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+impl From<&str> for NodeId {
+    fn from(id: &str) -> Self {
+        NodeId(id.to_string())
+    }
+}
+impl From<String> for NodeId {
+    fn from(id: String) -> Self {
+        NodeId(id)
+    }
+}
+impl PartialEq<str> for NodeId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+impl PartialEq<String> for NodeId {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The ids of a node and of its ancestors, from the document down.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NodePath(Vec<NodeId>);
+impl NodePath {
+    pub fn new() -> Self {
+        NodePath(vec![])
+    }
+    pub fn ids(&self) -> &[NodeId] {
+        &self.0
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// The id of the node at the end of the path.
+    pub fn last(&self) -> Option<&NodeId> {
+        self.0.last()
+    }
+    /// The path of the child `id` of the node at the end of this path.
+    pub fn child(&self, id: impl Into<NodeId>) -> NodePath {
+        let mut ids = self.0.clone();
+        ids.push(id.into());
+        NodePath(ids)
+    }
+    /// Whether the node of this path is `ancestor` or one of its descendants.
+    /// Ids are compared whole, so `1:2` is not an ancestor of `1:23`.
+    pub fn starts_with(&self, ancestor: &NodePath) -> bool {
+        self.0.starts_with(&ancestor.0)
+    }
+}
+impl From<Vec<NodeId>> for NodePath {
+    fn from(ids: Vec<NodeId>) -> Self {
+        NodePath(ids)
+    }
+}
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<&str> = self.0.iter().map(NodeId::as_str).collect();
+        f.write_str(&ids.join("|"))
+    }
+}
@@ -0,0 +1,134 @@
+use super::{BoundVariable, Color, Vector};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaintType {
+    Solid,
+    GradientLinear,
+    GradientRadial,
+    GradientAngular,
+    GradientDiamond,
+    Image,
+    Emoji,
+    Video,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// How layers blend with the layers behind them.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#blendmode-type)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BlendMode {
+    PassThrough,
+    Normal,
+    Darken,
+    Multiply,
+    LinearBurn,
+    ColorBurn,
+    Lighten,
+    Screen,
+    LinearDodge,
+    ColorDodge,
+    Overlay,
+    SoftLight,
+    HardLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScaleMode {
+    Fill,
+    Fit,
+    Tile,
+    Stretch,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// A position and color in a gradient.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+pub struct ColorStop {
+    /// Position of the stop along the gradient, between 0 and 1.
+    pub position: f64,
+    pub color: Color,
+}
+/// A fill, stroke or background of a node.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#paint-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Paint {
+    pub r#type: PaintType,
+    #[serde(default = "super::default_true")]
+    pub visible: bool,
+    #[serde(default = "super::default_one")]
+    pub opacity: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend_mode: Option<BlendMode>,
+    /// The color of SOLID paints.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The start, end and width handles of gradient paints, in the node's
+    /// normalized coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient_handle_positions: Option<Vec<Vector>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient_stops: Option<Vec<ColorStop>>,
+    /// How an IMAGE paint is scaled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale_mode: Option<ScaleMode>,
+    /// The reference of an IMAGE paint, to look up with the image fills API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bound_variables: Option<HashMap<String, BoundVariable>>,
+}
+impl Paint {
+    /// The color of a SOLID paint with the paint opacity applied.
+    pub fn solid_color(&self) -> Option<Color> {
+        match (self.r#type, self.color) {
+            (PaintType::Solid, Some(color)) => Some(Color { a: color.a * self.opacity, ..color }),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StrokeAlign {
+    Inside,
+    Outside,
+    Center,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StrokeWeights {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+/// The stroke of a node, gathered from its `strokes*` fields.
+#[derive(Debug, Clone)]
+pub struct Stroke<'a> {
+    pub paints: &'a [Paint],
+    pub weight: f64,
+    pub individual_weights: Option<StrokeWeights>,
+    pub align: StrokeAlign,
+    /// Lengths of the dashes and gaps of a dashed stroke.
+    pub dashes: &'a [f64],
+}
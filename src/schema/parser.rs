@@ -0,0 +1,170 @@
+use crate::schema::{ComponentMetadata, ComponentSetMetadata, File, Node, Style};
+use serde::Deserialize;
+use serde_json::{json, value::RawValue};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+/// Parses a Figma file straight from `reader`, without reading it into a
+/// string first.
+pub fn parse_reader<R: BufRead>(reader: R) -> Result<File, String> {
+    serde_json::from_reader(reader).map_err(|e| e.to_string())
+}
+/// Parses the Figma file at `path`.
+pub fn read_figma_file(path: impl AsRef<Path>) -> Result<File, String> {
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    parse_reader(BufReader::new(file))
+}
+
+/// The id, name, type and children of a node, borrowing the strings from the
+/// input wherever they need no unescaping.
+#[derive(Debug, Deserialize)]
+pub struct NodeOutline<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub node_type: Cow<'a, str>,
+    #[serde(default, borrow)]
+    pub children: Vec<NodeOutline<'a>>,
+}
+/// The node tree of a file without the node properties, e.g. for a tree view.
+#[derive(Debug, Deserialize)]
+pub struct FileOutline<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub document: NodeOutline<'a>,
+}
+pub fn parse_outline(json: &str) -> Result<FileOutline<'_>, String> {
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
+
+#[derive(Deserialize)]
+struct PageHeader {
+    id: String,
+    name: String,
+}
+/// A page whose nodes are only parsed when it is loaded.
+pub struct LazyPage {
+    pub id: String,
+    pub name: String,
+    raw: Box<RawValue>,
+}
+impl LazyPage {
+    /// Parses the page and all its nodes.
+    pub fn load(&self) -> Result<Node, String> {
+        serde_json::from_str(self.raw.get()).map_err(|e| e.to_string())
+    }
+    /// The page without its children.
+    fn stub(&self) -> Result<Node, String> {
+        serde_json::from_value(json!({ "id": self.id, "name": self.name, "type": "CANVAS" }))
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct LazyDocument {
+    id: String,
+    name: String,
+    #[serde(default)]
+    children: Vec<Box<RawValue>>,
+}
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LazyFileData {
+    document: LazyDocument,
+    name: String,
+    version: String,
+    #[serde(default)]
+    components: HashMap<String, ComponentMetadata>,
+    #[serde(default)]
+    component_sets: HashMap<String, ComponentSetMetadata>,
+    #[serde(default)]
+    styles: HashMap<String, Style>,
+}
+
+/// A Figma file whose pages are kept as raw JSON until they are opened.
+pub struct LazyFile {
+    pub name: String,
+    pub version: String,
+    document_id: String,
+    document_name: String,
+    components: HashMap<String, ComponentMetadata>,
+    component_sets: HashMap<String, ComponentSetMetadata>,
+    styles: HashMap<String, Style>,
+    pages: Vec<LazyPage>,
+}
+impl LazyFile {
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, String> {
+        Self::from_data(serde_json::from_reader(reader).map_err(|e| e.to_string())?)
+    }
+    /// Parses the Figma file at `path`, reading it into memory first since
+    /// that is faster than from a reader.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, String> {
+        Self::from_slice(&fs::read(path).map_err(|e| e.to_string())?)
+    }
+    /// Parses a file already in memory, which is faster than from a reader.
+    pub fn from_slice(json: &[u8]) -> Result<Self, String> {
+        Self::from_data(serde_json::from_slice(json).map_err(|e| e.to_string())?)
+    }
+    fn from_data(data: LazyFileData) -> Result<Self, String> {
+        let pages = data
+            .document
+            .children
+            .into_iter()
+            .map(|raw| {
+                let header: PageHeader =
+                    serde_json::from_str(raw.get()).map_err(|e| e.to_string())?;
+                Ok(LazyPage { id: header.id, name: header.name, raw })
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(LazyFile {
+            name: data.name,
+            version: data.version,
+            document_id: data.document.id,
+            document_name: data.document.name,
+            components: data.components,
+            component_sets: data.component_sets,
+            styles: data.styles,
+            pages,
+        })
+    }
+    pub fn pages(&self) -> &[LazyPage] {
+        &self.pages
+    }
+    pub fn page(&self, id: &str) -> Option<&LazyPage> {
+        self.pages.iter().find(|page| page.id == id)
+    }
+    /// The file with the pages `open` loaded, and the other pages empty.
+    pub fn to_file(&self, open: &[&str]) -> Result<File, String> {
+        let mut document: Node = serde_json::from_value(json!({
+            "id": self.document_id,
+            "name": self.document_name,
+            "type": "DOCUMENT",
+        }))
+        .map_err(|e| e.to_string())?;
+        let pages = self
+            .pages
+            .iter()
+            .map(|page| match open.contains(&page.id.as_str()) {
+                true => page.load(),
+                false => page.stub(),
+            })
+            .collect::<Result<_, String>>()?;
+        document.children = Some(pages);
+        Ok(File {
+            document,
+            name: self.name.clone(),
+            version: self.version.clone(),
+            components: self.components.clone(),
+            component_sets: self.component_sets.clone(),
+            styles: self.styles.clone(),
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StyleType {
+    Fill,
+    Text,
+    Effect,
+    Grid,
+}
+/// An entry of the file level `styles` map.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#style-type)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Style {
+    /// The key uniquely identifying this style across files.
+    pub key: String,
+    /// The name of the style, e.g. `sprout/label/font/m-emphasized`.
+    pub name: String,
+    pub style_type: StyleType,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub remote: bool,
+}
@@ -0,0 +1,80 @@
+use super::Paint;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TextAlignHorizontal {
+    Left,
+    Right,
+    Center,
+    Justified,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TextDecoration {
+    None,
+    Strikethrough,
+    Underline,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TextCase {
+    Original,
+    Upper,
+    Lower,
+    Title,
+    SmallCaps,
+    SmallCapsForced,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// The list type of a line of a TEXT node.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LineType {
+    #[default]
+    None,
+    Ordered,
+    Unordered,
+    /// A value added to the API after this crate.
+    #[serde(other)]
+    Other,
+}
+/// The typography of a TEXT node, or of a range of its characters in
+/// `styleOverrideTable`, where only the overridden fields are set.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#typestyle-type)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_weight: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    /// Line height in px.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_height_px: Option<f64>,
+    /// Letter spacing in px.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letter_spacing: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_align_horizontal: Option<TextAlignHorizontal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_decoration: Option<TextDecoration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_case: Option<TextCase>,
+    /// The fills of the characters, only set in `styleOverrideTable`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fills: Option<Vec<Paint>>,
+}
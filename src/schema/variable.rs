@@ -0,0 +1,150 @@
+use super::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A reference to a variable, e.g. in `boundVariables`.
+///
+/// [Figma documentation](https://www.figma.com/developers/api#variablealias-type)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct VariableAlias {
+    /// Always `VARIABLE_ALIAS`.
+    pub r#type: String,
+    /// The id of the variable, e.g. `VariableID:2799168d.../2612:91` for a
+    /// variable of a library.
+    pub id: String,
+}
+/// A value of a node's `boundVariables` map. Depending on the field, a variable
+/// is bound directly (`paddingLeft`), per item (`fills`) or per sub-field
+/// (`size.x`).
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum BoundVariable {
+    Alias(VariableAlias),
+    List(Vec<VariableAlias>),
+    Map(HashMap<String, VariableAlias>),
+}
+impl BoundVariable {
+    /// The first alias bound to the field: of a map, the alias of the
+    /// sub-field first in name order, so that the choice doesn't depend on
+    /// the order of the map.
+    pub fn first(&self) -> Option<&VariableAlias> {
+        match self {
+            BoundVariable::Alias(alias) => Some(alias),
+            BoundVariable::List(aliases) => aliases.first(),
+            BoundVariable::Map(aliases) => aliases
+                .iter()
+                .min_by_key(|(field, _)| field.as_str())
+                .map(|(_, alias)| alias),
+        }
+    }
+    /// The alias bound to the sub-field `field`, e.g. `x` of `size`.
+    pub fn field(&self, field: &str) -> Option<&VariableAlias> {
+        match self {
+            BoundVariable::Map(aliases) => aliases.get(field),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VariableResolvedType {
+    Boolean,
+    Float,
+    String,
+    Color,
+}
+/// The value of a variable in one mode.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum VariableValue {
+    Boolean(bool),
+    Float(f64),
+    String(String),
+    Alias(VariableAlias),
+    Color(Color),
+}
+/// [Figma documentation](https://www.figma.com/developers/api#variables-types)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub id: String,
+    /// The name of the variable, e.g. `color/text/primary`.
+    pub name: String,
+    /// The key to use with the variables of a library in other files.
+    pub key: String,
+    pub variable_collection_id: String,
+    pub resolved_type: VariableResolvedType,
+    /// The values of the variable, keyed by mode id.
+    pub values_by_mode: HashMap<String, VariableValue>,
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub description: String,
+}
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VariableMode {
+    pub mode_id: String,
+    /// The name of the mode, e.g. `Light` or `Dark`.
+    pub name: String,
+}
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VariableCollection {
+    pub id: String,
+    pub name: String,
+    pub key: String,
+    pub modes: Vec<VariableMode>,
+    pub default_mode_id: String,
+    #[serde(default)]
+    pub remote: bool,
+    #[serde(default)]
+    pub variable_ids: Vec<String>,
+}
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalVariables {
+    #[serde(default)]
+    pub variables: HashMap<String, Variable>,
+    #[serde(default)]
+    pub variable_collections: HashMap<String, VariableCollection>,
+}
+/// The response of `GET /v1/files/:key/variables/local`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LocalVariablesResponse {
+    pub meta: LocalVariables,
+}
+/// A variable published by a library file. Published variables carry no
+/// values.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishedVariable {
+    pub id: String,
+    pub subscribed_id: String,
+    pub name: String,
+    pub key: String,
+    pub variable_collection_id: String,
+    pub resolved_data_type: VariableResolvedType,
+}
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishedVariableCollection {
+    pub id: String,
+    pub subscribed_id: String,
+    pub name: String,
+    pub key: String,
+}
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishedVariables {
+    #[serde(default)]
+    pub variables: HashMap<String, PublishedVariable>,
+    #[serde(default)]
+    pub variable_collections: HashMap<String, PublishedVariableCollection>,
+}
+/// The response of `GET /v1/files/:key/variables/published`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublishedVariablesResponse {
+    pub meta: PublishedVariables,
+}
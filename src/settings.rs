@@ -0,0 +1,104 @@
+use crate::files::{config_dir, RecentFiles};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+const SETTINGS_FILE: &str = "settings.json";
+const TOKEN_FILE: &str = "token";
+/// Overrides the saved personal access token.
+pub const TOKEN_ENV: &str = "FIGMA_TOKEN";
+
+/// The settings of the app, saved in the config directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The key of the Figma file fetched last.
+    pub file_id: String,
+    pub recent_files: RecentFiles,
+    pub window: WindowSettings,
+    pub generator: GeneratorOptions,
+}
+
+/// The size of the main window and of its panes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub width: f32,
+    pub height: f32,
+    /// The share of the height taken by the node tree when the coverage
+    /// report is shown below it, between 0 and 1.
+    pub tree_ratio: f32,
+}
+impl Default for WindowSettings {
+    fn default() -> Self {
+        WindowSettings { width: 1024.0, height: 768.0, tree_ratio: 0.6 }
+    }
+}
+
+/// Options of the code generation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeneratorOptions {
+    /// Mark the generated components with diagnostics in the tree.
+    pub diagnostics: bool,
+    /// The directory the design tokens are exported to.
+    pub export_dir: String,
+}
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions { diagnostics: true, export_dir: ".".to_string() }
+    }
+}
+
+impl Settings {
+    pub fn path() -> PathBuf {
+        config_dir().join(SETTINGS_FILE)
+    }
+    /// The saved settings, the defaults if they can't be read.
+    pub fn load() -> Self {
+        fs::read_to_string(Settings::path())
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    pub fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(config_dir())?;
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(Settings::path(), json)
+    }
+}
+
+/// The personal access token from `FIGMA_TOKEN`, or else the saved one.
+pub fn load_token() -> Option<String> {
+    env_token().or_else(|| {
+        fs::read_to_string(config_dir().join(TOKEN_FILE))
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+    })
+}
+/// The personal access token set in `FIGMA_TOKEN`.
+pub fn env_token() -> Option<String> {
+    env::var(TOKEN_ENV).ok().filter(|token| !token.is_empty())
+}
+/// Saves the token apart from the settings, readable by the user only.
+pub fn save_token(token: &str) -> io::Result<()> {
+    fs::create_dir_all(config_dir())?;
+    let path = config_dir().join(TOKEN_FILE);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // the mode only applies to new files
+        if path.exists() {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    options.open(path)?.write_all(token.as_bytes())
+}
@@ -1 +1 @@
-pub mod tree;
\ No newline at end of file
+pub mod tree;
@@ -1,17 +1,21 @@
 use crate::{
-    schema::{File as FigmaFile, Node as FigmaNode, NodeType as FigmaNodeType},
-    Message,
+    app::Message,
+    gen::{
+        component_generator::Generators, context::GenContext, diagnostic::Diagnostic,
+        node_util::NodeIndex,
+    },
+    schema::{File as FigmaFile, Node as FigmaNode, NodeId, NodePath, NodeType as FigmaNodeType},
 };
 use iced::{
     widget::{
-        button, horizontal_space, row,
-        svg::{Handle, Svg},
-        text, Column, Container,
+        button, container, horizontal_space, row,
+        svg::{self, Handle, Svg},
+        text, tooltip, Column, Container,
     },
-    Element, Font, Length, Padding,
+    Alignment, Element, Font, Length, Padding, Theme,
 };
 
-use std::{fs, sync::Arc};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum NodeMessage {
@@ -24,95 +28,140 @@ pub struct TreeNode {
     pub node_type: NodeType,
     pub is_expanded: bool,
     pub children: Vec<TreeNode>,
-    pub id: String,
-    pub id_paths: String,
-    pub node_paths: String,
+    pub id: NodeId,
+    /// The ids from the document down to this node.
+    pub path: NodePath,
+    pub status: GenStatus,
 }
 #[derive(Debug, Clone)]
 pub enum NodeType {
     Unknown,
     Canvas,
     Component,
+    ComponentSet,
     Instance,
     Frame,
+    Group,
+    Section,
+    Vector,
     Text,
 }
-impl From<FigmaNodeType> for NodeType {
-    fn from(value: FigmaNodeType) -> Self {
+impl From<&FigmaNodeType> for NodeType {
+    fn from(value: &FigmaNodeType) -> Self {
         match value {
             FigmaNodeType::Canvas => NodeType::Canvas,
             FigmaNodeType::Instance => NodeType::Instance,
             FigmaNodeType::Frame => NodeType::Frame,
-            FigmaNodeType::Text => NodeType::Text,
+            FigmaNodeType::Group => NodeType::Group,
+            FigmaNodeType::Section => NodeType::Section,
+            FigmaNodeType::Text | FigmaNodeType::TextPath => NodeType::Text,
             FigmaNodeType::Component => NodeType::Component,
+            FigmaNodeType::ComponentSet => NodeType::ComponentSet,
+            FigmaNodeType::Vector
+            | FigmaNodeType::BooleanOperation
+            | FigmaNodeType::Star
+            | FigmaNodeType::Line
+            | FigmaNodeType::Ellipse
+            | FigmaNodeType::RegularPolygon
+            | FigmaNodeType::Rectangle => NodeType::Vector,
             _ => NodeType::Unknown,
         }
     }
 }
+/// What generating a node gives, shown next to it in the tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GenStatus {
+    /// No library component matches the node.
+    #[default]
+    None,
+    /// The node generates the library `component`. The `diagnostics` of the
+    /// generation are only known once the node was generated.
+    Generated { component: String, diagnostics: Option<Vec<Diagnostic>> },
+}
 impl TreeNode {
-    pub fn new(
-        name: String,
-        node_type: NodeType,
-        parent_path: String,
-        id: String,
-        node_paths: String,
-    ) -> Self {
-        let id_paths = {
-            if parent_path == "" {
-                id.clone()
-            } else {
-                format!("{}|{}", parent_path, id)
-            }
-        };
+    pub fn new(name: String, node_type: NodeType, path: NodePath) -> Self {
         TreeNode {
-            id: id.clone(),
+            id: path.last().cloned().unwrap_or_else(|| NodeId::new("")),
             name,
             node_type,
             is_expanded: false,
             children: vec![],
-            id_paths,
-            node_paths,
+            path,
+            status: GenStatus::None,
+        }
+    }
+    /// Marks the nodes of the tree a library component generator matches,
+    /// without generating them.
+    pub fn update_status(&mut self, index: &NodeIndex, generators: &Generators) {
+        let generator = index
+            .get(&self.id)
+            .and_then(|node| generators.generator_for(node))
+            .filter(|generator| generator.is_library_component());
+        self.status = match generator {
+            Some(generator) => GenStatus::Generated {
+                component: generator.component_name().to_string(),
+                diagnostics: None,
+            },
+            None => GenStatus::None,
+        };
+        for child in &mut self.children {
+            child.update_status(index, generators);
+        }
+    }
+    /// Generates the marked nodes of the tree and records their diagnostics.
+    pub fn update_diagnostics(&mut self, index: &NodeIndex, ctx: &GenContext) {
+        if let (GenStatus::Generated { .. }, Some(node)) = (&self.status, index.get(&self.id)) {
+            ctx.generators.gen_component(node, ctx);
+            self.set_diagnostics(ctx.take_diagnostics());
+        }
+        for child in &mut self.children {
+            child.update_diagnostics(index, ctx);
+        }
+    }
+    /// Records the diagnostics of generating the node.
+    pub fn set_diagnostics(&mut self, found: Vec<Diagnostic>) {
+        if let GenStatus::Generated { diagnostics, .. } = &mut self.status {
+            *diagnostics = Some(found);
         }
     }
-    pub fn find_child(&mut self, path: String) -> Option<&mut TreeNode> {
-        if self.id_paths == path {
+    pub fn find_child(&mut self, path: &NodePath) -> Option<&mut TreeNode> {
+        if self.path == *path {
             return Some(self);
         }
-        if path.starts_with(self.id_paths.as_str()) {
-            let remain_path = &path[self.id_paths.len() + 1..];
-            let paths: Vec<&str> = remain_path.split('|').collect();
-            if let Some(id) = paths.first() {
-                let child_id = id.to_string();
-                for c in self.children.iter_mut() {
-                    if c.id == child_id {
-                        return c.find_child(path);
-                    }
+        if path.starts_with(&self.path) {
+            let child_id = &path.ids()[self.path.len()];
+            for c in self.children.iter_mut() {
+                if c.id == *child_id {
+                    return c.find_child(path);
                 }
             }
         }
         None
     }
+    /// The depth below the pages.
+    fn depth(&self) -> usize {
+        self.path.len().saturating_sub(2)
+    }
     fn icon<'a, Message>(codepoint: char) -> Element<'a, Message> {
         const ICON_FONT: Font = Font::with_name("my_fonts");
         text(codepoint).font(ICON_FONT).into()
     }
 
-    fn type_svg(&self) -> Element<Message> {
-        let svg_path = match self.node_type {
-            NodeType::Canvas => "assets/canvas.svg",
-            NodeType::Component => "assets/component.svg",
-            NodeType::Instance => "assets/instance.svg",
-            NodeType::Frame => "assets/frame.svg",
-            NodeType::Text => "assets/text.svg",
-            NodeType::Unknown => "assets/unknown.svg",
+    fn type_svg(&self) -> Element<'_, Message> {
+        let svg_content: &'static [u8] = match self.node_type {
+            NodeType::Canvas => include_bytes!("../../assets/canvas.svg"),
+            NodeType::Component => include_bytes!("../../assets/component.svg"),
+            NodeType::ComponentSet => include_bytes!("../../assets/component_set.svg"),
+            NodeType::Instance => include_bytes!("../../assets/instance.svg"),
+            NodeType::Frame => include_bytes!("../../assets/frame.svg"),
+            NodeType::Group => include_bytes!("../../assets/group.svg"),
+            NodeType::Section => include_bytes!("../../assets/section.svg"),
+            NodeType::Vector => include_bytes!("../../assets/vector.svg"),
+            NodeType::Text => include_bytes!("../../assets/text.svg"),
+            NodeType::Unknown => include_bytes!("../../assets/unknown.svg"),
         };
 
-        let svg_content = fs::read_to_string(svg_path).unwrap_or_else(|e| {
-            eprintln!("Error reading SVG file {}: {}", svg_path, e);
-            String::from("<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>")
-        });
-
-        let handle = Handle::from_memory(svg_content.into_bytes());
+        let handle = Handle::from_memory(svg_content);
         let svg = Svg::new(handle).width(Length::Fixed(20.0));
 
         Container::new(svg)
@@ -120,25 +169,57 @@ impl TreeNode {
             .into()
     }
 
-    pub fn view(&self) -> Element<Message> {
+    /// The component the node generates, with a warning marker listing the
+    /// diagnostics of the generation.
+    fn status_badge(&self) -> Option<Element<'_, Message>> {
+        let GenStatus::Generated { component, diagnostics } = &self.status else {
+            return None;
+        };
+        let badge = container(text(component.as_str()).size(12))
+            .padding(Padding { top: 1.0, right: 6.0, bottom: 1.0, left: 6.0 })
+            .style(container::rounded_box);
+        let diagnostics = match diagnostics {
+            Some(diagnostics) if !diagnostics.is_empty() => diagnostics,
+            _ => return Some(badge.into()),
+        };
+        let warning =
+            Svg::new(Handle::from_memory(include_bytes!("../../assets/warning.svg").as_slice()))
+                .width(Length::Fixed(16.0))
+                .style(|theme: &Theme, _| svg::Style { color: Some(theme.palette().danger) });
+        let hint: Vec<String> = diagnostics.iter().map(Diagnostic::to_string).collect();
+        let marker = tooltip(warning, text(hint.join("\n")).size(12), tooltip::Position::Right)
+            .style(container::rounded_box);
+        Some(
+            row![badge, marker]
+                .spacing(4)
+                .align_y(Alignment::Center)
+                .into(),
+        )
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
         // expand/collapse button
         let mut column = Column::new();
         let expand_text = Self::icon(if self.is_expanded { '\u{E803}' } else { '\u{E802}' });
         let expand_button = button(expand_text)
             .style(button::text)
             .width(22)
-            .on_press(Message::TreeNode(self.id_paths.clone(), NodeMessage::Toggle));
+            .on_press(Message::TreeNode(self.path.clone(), NodeMessage::Toggle));
 
         let type_svg = self.type_svg();
 
         let label = button(self.name.as_str())
             .style(button::text)
-            .on_press(Message::TreeNode(self.id_paths.clone(), NodeMessage::Select));
-        let path_depth = self.id_paths.split('|').collect::<Vec<_>>().len();
+            .on_press(Message::TreeNode(self.path.clone(), NodeMessage::Select));
 
-        let left_padding = horizontal_space().width((path_depth - 1) as u16 * 10);
+        let left_padding = horizontal_space().width(self.depth() as u16 * 10);
 
-        column = column.push(row!(left_padding, expand_button, type_svg, label));
+        let mut node_row =
+            row!(left_padding, expand_button, type_svg, label).align_y(Alignment::Center);
+        if let Some(badge) = self.status_badge() {
+            node_row = node_row.push(badge);
+        }
+        column = column.push(node_row);
 
         if self.is_expanded {
             for child in &self.children {
@@ -160,10 +241,10 @@ impl TreeNode {
 
 pub async fn parse_file_to_tree(file: Arc<FigmaFile>) -> Result<Vec<TreeNode>, String> {
     let mut result = Vec::new();
-    let document_id = file.document.id.clone();
+    let document_path = NodePath::new().child(file.document.id.as_str());
     if let Some(pages) = &file.document.children {
         for page in pages {
-            if let Ok(page) = parse_node(page, "".to_string(), document_id.clone()) {
+            if let Ok(page) = parse_node(page, &document_path) {
                 result.push(page);
             }
         }
@@ -171,21 +252,14 @@ pub async fn parse_file_to_tree(file: Arc<FigmaFile>) -> Result<Vec<TreeNode>, S
     Ok(result)
 }
 
-fn parse_node(
-    node: &FigmaNode,
-    parent_path: String,
-    parent_node_paths: String,
-) -> Result<TreeNode, String> {
+fn parse_node(node: &FigmaNode, parent_path: &NodePath) -> Result<TreeNode, String> {
     let name = node.name.clone();
-    let node_type = node.r#type.into();
-    let id = node.id.clone();
-    let node_paths = format!("{}|{}", parent_node_paths, id);
-    let mut tree_node = TreeNode::new(name, node_type, parent_path, id, node_paths);
+    let node_type = (&node.r#type).into();
+    let path = parent_path.child(node.id.as_str());
+    let mut tree_node = TreeNode::new(name, node_type, path);
     if let Some(children) = &node.children {
         for child in children {
-            if let Ok(child) =
-                parse_node(child, tree_node.id_paths.clone(), tree_node.node_paths.clone())
-            {
+            if let Ok(child) = parse_node(child, &tree_node.path) {
                 tree_node.children.push(child);
             }
         }
@@ -0,0 +1,83 @@
+mod support;
+
+use figma2sprout::{
+    cache::{self, CacheStatus},
+    figma_api::{FigmaApi, API_ROOT_ENV},
+};
+use std::{env, fs, time::Duration};
+use support::MockServer;
+
+const KEY: &str = "abc123";
+
+fn file_json(version: &str, last_modified: &str) -> String {
+    format!(
+        r#"{{"name":"Sprout","version":"{}","lastModified":"{}","document":{{"id":"0:0","name":"Document","type":"DOCUMENT","children":[]}}}}"#,
+        version, last_modified
+    )
+}
+fn serve_version(server: &MockServer, version: &str, last_modified: &str) {
+    let json = file_json(version, last_modified);
+    server.route(&format!("/files/{}?depth=1", KEY), 200, json.clone());
+    server.route(&format!("/files/{}", KEY), 200, json);
+}
+fn fetch() -> CacheStatus {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let api = FigmaApi::new("token").unwrap();
+    let (path, status) = runtime.block_on(cache::fetch_file(&api, KEY)).unwrap();
+    assert!(path.is_file());
+    status
+}
+
+// The cache directory and the API root come from the environment, so
+// everything runs in one test.
+#[test]
+fn files_are_downloaded_only_when_they_changed() {
+    let dir = env::temp_dir().join(format!("figma2sprout-cache-{}", std::process::id()));
+    env::set_var("XDG_CACHE_HOME", &dir);
+    let server = MockServer::start();
+    env::set_var(API_ROOT_ENV, server.url());
+    let full_file = format!("/files/{}", KEY);
+
+    serve_version(&server, "1", "2024-01-01T00:00:00Z");
+    assert_eq!(fetch(), CacheStatus::New);
+    assert!(server.requests().contains(&full_file));
+    let entry = cache::entry(KEY).unwrap();
+    assert_eq!(entry.meta.name, "Sprout");
+    assert_eq!(entry.meta.version, "1");
+    // the variables API answered 404, nothing to save
+    assert!(!entry.dir().join("variables.json").exists());
+
+    server.clear_requests();
+    assert_eq!(fetch(), CacheStatus::Fresh);
+    assert_eq!(server.requests(), vec![format!("/files/{}?depth=1", KEY)]);
+
+    server.clear_requests();
+    serve_version(&server, "1", "2024-02-01T00:00:00Z");
+    assert_eq!(fetch(), CacheStatus::Updated);
+    assert!(server.requests().contains(&full_file));
+    assert_eq!(cache::entry(KEY).unwrap().meta.last_modified, "2024-02-01T00:00:00Z");
+
+    // a removed file is downloaded again
+    fs::remove_file(entry.file_path()).unwrap();
+    server.clear_requests();
+    assert_eq!(fetch(), CacheStatus::Updated);
+    assert!(server.requests().contains(&full_file));
+
+    assert_eq!(cache::entries().len(), 1);
+    assert!(cache::prune(Some(Duration::from_secs(3600)))
+        .unwrap()
+        .is_empty());
+    assert_eq!(cache::prune(None).unwrap().len(), 1);
+    assert!(cache::entries().is_empty());
+    assert!(cache::entry(KEY).is_none());
+
+    // errors are not cached
+    server.route(&format!("/files/{}?depth=1", KEY), 403, r#"{"status":403}"#);
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let api = FigmaApi::new("token").unwrap();
+    assert!(runtime.block_on(cache::fetch_file(&api, KEY)).is_err());
+    assert!(cache::entry(KEY).is_none());
+
+    env::remove_var(API_ROOT_ENV);
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,68 @@
+use figma2sprout::gen::{
+    component_generator::Generators, context::GenContext, node_util::convert_json_to_figma,
+    query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+};
+use serde_json::json;
+
+#[test]
+fn generates_the_shown_components_nested_in_other_layers() {
+    let badge = |id: &str, text: &str| {
+        json!({
+            "id": id,
+            "name": "Badge",
+            "type": "INSTANCE",
+            "componentProperties": { "Text#1:1": { "type": "TEXT", "value": text } }
+        })
+    };
+    let mut hidden = badge("1:4", "hidden");
+    hidden["visible"] = json!(false);
+    let file = json!({
+        "name": "Frames",
+        "version": "1",
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{
+                "id": "1:1",
+                "name": "Card",
+                "type": "FRAME",
+                "children": [
+                    {
+                        "id": "1:2",
+                        "name": "Header",
+                        "type": "INSTANCE",
+                        "componentProperties": {
+                            "Show count#2:1": { "type": "BOOLEAN", "value": false }
+                        },
+                        "children": [
+                            badge("1:3", "1"),
+                            {
+                                "id": "1:5",
+                                "name": "Count",
+                                "type": "INSTANCE",
+                                "componentPropertyReferences": { "visible": "Show count#2:1" },
+                                "children": [badge("1:6", "2")]
+                            }
+                        ]
+                    },
+                    hidden
+                ]
+            }]
+        }
+    });
+    let file = convert_json_to_figma(file.to_string()).unwrap();
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+
+    let card = find_by_id(&file.document, "1:1").unwrap();
+    let jsx = generators
+        .gen_component(card, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    assert_eq!(jsx, "<Box>\n  <Badge badgeContent=\"1\" />\n</Box>\n");
+}
@@ -0,0 +1,86 @@
+use figma2sprout::{
+    gen::{
+        component_generator::Generators,
+        context::GenContext,
+        coverage::{coverage_report, CoverageReport},
+        tokens::TokenResolver,
+        validation::PropertyDefinitions,
+    },
+    schema::parser::read_figma_file,
+};
+
+fn demo_report() -> CoverageReport {
+    let file = read_figma_file("demo.json").unwrap();
+    let definitions = PropertyDefinitions::from_file(&file);
+    let tokens = TokenResolver::from_file(&file);
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+    coverage_report(&ctx)
+}
+
+#[test]
+fn instances_are_grouped_by_component_set() {
+    let report = demo_report();
+    let button = report
+        .components
+        .iter()
+        .find(|c| c.name == "Button")
+        .unwrap();
+    assert_eq!(button.instances, 6);
+    assert_eq!(button.generated, 6);
+    assert!(button.generated_as.contains("Button"));
+    assert!(button.unmapped_properties.is_empty());
+
+    // the component set, not the generated component, names the group
+    let input = report
+        .components
+        .iter()
+        .find(|c| c.name == "InputButton")
+        .unwrap();
+    assert!(input.generated_as.contains("TextField"));
+
+    let icon = report
+        .components
+        .iter()
+        .find(|c| c.name == "icons/outline/star")
+        .unwrap();
+    assert!(!icon.has_generator());
+    assert_eq!(icon.clean, 0);
+
+    // the generated component sets come first
+    let first_missing = report
+        .components
+        .iter()
+        .position(|c| !c.has_generator())
+        .unwrap();
+    assert!(report.components[first_missing..]
+        .iter()
+        .all(|c| !c.has_generator()));
+    assert_eq!(report.instances(), report.components.iter().map(|c| c.instances).sum::<usize>());
+    assert!(report.clean() <= report.generated());
+}
+
+#[test]
+fn unmapped_properties_are_listed_without_their_id() {
+    let report = demo_report();
+    let avatar = report
+        .components
+        .iter()
+        .find(|c| c.name == "Avatar")
+        .unwrap();
+    assert_eq!(avatar.unmapped_properties.iter().collect::<Vec<_>>(), ["Has badge"]);
+}
+
+#[test]
+fn report_is_a_table_and_json() {
+    let report = demo_report();
+    let table = report.to_table();
+    assert!(table.starts_with("Component"));
+    assert!(table.lines().any(|line| line.starts_with("Button ")));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    let components = json["components"].as_array().unwrap();
+    assert_eq!(components.len(), report.components.len());
+    assert!(components[0].get("unmappedProperties").is_some());
+}
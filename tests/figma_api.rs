@@ -0,0 +1,119 @@
+mod support;
+
+use figma2sprout::figma_api::{ApiConfig, ApiError, FigmaApi};
+use std::time::{Duration, Instant};
+use support::{MockResponse, MockServer};
+
+fn config(server: &MockServer, max_retries: u32) -> ApiConfig {
+    ApiConfig {
+        root: server.url(),
+        max_retries,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(40),
+        ..ApiConfig::default()
+    }
+}
+fn api(server: &MockServer, max_retries: u32) -> FigmaApi {
+    FigmaApi::with_config("token", config(server, max_retries)).unwrap()
+}
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+#[test]
+fn server_errors_are_retried() {
+    let server = MockServer::start();
+    server.queue("/files/key", MockResponse::new(500, "oops"));
+    server.queue("/files/key", MockResponse::new(502, "oops"));
+    server.route("/files/key", 200, "{}");
+    assert_eq!(block_on(api(&server, 4).fetch_figma_file("key")).unwrap(), "{}");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn rate_limits_honour_retry_after() {
+    let server = MockServer::start();
+    server.queue("/files/key", MockResponse::new(429, "slow down").header("Retry-After", "1"));
+    server.route("/files/key", 200, "{}");
+    let config = ApiConfig { max_backoff: Duration::from_secs(5), ..config(&server, 4) };
+    let api = FigmaApi::with_config("token", config).unwrap();
+    let start = Instant::now();
+    assert!(block_on(api.fetch_figma_file("key")).is_ok());
+    // longer than the backoff of the config
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn retry_after_is_limited_to_the_max_backoff() {
+    let server = MockServer::start();
+    server.queue("/files/key", MockResponse::new(429, "slow down").header("Retry-After", "3600"));
+    server.route("/files/key", 200, "{}");
+    let start = Instant::now();
+    assert!(block_on(api(&server, 4).fetch_figma_file("key")).is_ok());
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn retries_give_up_after_the_limit() {
+    let server = MockServer::start();
+    server.route("/files/key", 503, "unavailable");
+    let error = block_on(api(&server, 2).fetch_figma_file("key")).unwrap_err();
+    assert_eq!(error.status().map(|status| status.as_u16()), Some(503));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn client_errors_are_not_retried() {
+    let server = MockServer::start();
+    server.route("/files/key", 403, r#"{"status":403,"err":"Invalid token"}"#);
+    let error = block_on(api(&server, 4).fetch_figma_file("key")).unwrap_err();
+    assert!(error.to_string().contains("Invalid token"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn images_are_fetched_in_batches() {
+    let server = MockServer::start();
+    server.route("/images/key", 200, r#"{"err":null,"images":{"1:1":"https://images/1"}}"#);
+    let ids: Vec<String> = (0..120).map(|i| format!("1:{}", i)).collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    let images = block_on(api(&server, 0).fetch_images("key", &ids, "png", 2.0)).unwrap();
+    assert_eq!(images.get("1:1"), Some(&Some("https://images/1".to_string())));
+
+    let mut batches: Vec<usize> = server
+        .requests()
+        .iter()
+        .map(|path| {
+            path.split("ids=")
+                .nth(1)
+                .unwrap()
+                .split('&')
+                .next()
+                .unwrap()
+                .split("%2C")
+                .count()
+        })
+        .collect();
+    batches.sort();
+    assert_eq!(batches, vec![20, 50, 50]);
+}
+
+#[test]
+fn ids_are_encoded_in_the_query() {
+    let server = MockServer::start();
+    server.route("/files/key/nodes", 200, "{}");
+    block_on(api(&server, 0).fetch_nodes("key", &["I1:2;3:4", "5:6"])).unwrap();
+    assert_eq!(server.requests(), vec!["/files/key/nodes?ids=I1%3A2%3B3%3A4%2C5%3A6"]);
+}
+
+#[test]
+fn image_errors_fail_the_request() {
+    let server = MockServer::start();
+    server.route("/images/key", 200, r#"{"err":"Render timeout","images":{}}"#);
+    let error = block_on(api(&server, 0).fetch_images("key", &["1:1"], "png", 1.0)).unwrap_err();
+    assert!(matches!(&error, ApiError::Images(err) if err == "Render timeout"));
+    assert_eq!(error.status(), None);
+    assert_eq!(error.to_string(), "could not render the images: Render timeout");
+}
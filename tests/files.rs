@@ -0,0 +1,71 @@
+use figma2sprout::{
+    cache,
+    files::{file_arg, file_cache_dir, files_dir, variables_path, RecentFiles},
+};
+use std::path::Path;
+
+fn args(args: &[&str]) -> impl Iterator<Item = String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    args.into_iter()
+}
+
+#[test]
+fn reads_the_file_argument() {
+    assert_eq!(file_arg(args(&["figma2sprout"])), Ok(None));
+    assert_eq!(
+        file_arg(args(&["figma2sprout", "--file", "a.json"])),
+        Ok(Some("a.json".to_string()))
+    );
+    assert_eq!(file_arg(args(&["figma2sprout", "--file=b.json"])), Ok(Some("b.json".to_string())));
+    // the last one wins
+    assert_eq!(
+        file_arg(args(&["figma2sprout", "--file", "a.json", "--file=b.json"])),
+        Ok(Some("b.json".to_string()))
+    );
+    assert!(file_arg(args(&["figma2sprout", "--file"])).is_err());
+    assert!(file_arg(args(&["figma2sprout", "a.json"])).is_err());
+}
+
+#[test]
+fn keeps_the_most_recent_files_first() {
+    let mut recent = RecentFiles::new();
+    assert_eq!(recent.most_recent(), None);
+    for i in 0..12 {
+        recent.push(&format!("{}.json", i));
+    }
+    assert_eq!(recent.paths().len(), 10);
+    assert_eq!(recent.most_recent(), Some("11.json"));
+    assert_eq!(recent.paths().last().map(String::as_str), Some("2.json"));
+
+    // opening a file again moves it to the front without duplicating it
+    recent.push("5.json");
+    assert_eq!(recent.paths().len(), 10);
+    assert_eq!(recent.paths()[..2], ["5.json", "11.json"]);
+    assert_eq!(
+        recent
+            .paths()
+            .iter()
+            .filter(|path| *path == "5.json")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn rejects_file_keys_that_are_not_a_single_directory() {
+    assert_eq!(file_cache_dir("abc-123_X").unwrap(), files_dir().join("abc-123_X"));
+    for key in ["", ".", "..", "../abc", "a/b", "/abc", "a b"] {
+        assert!(file_cache_dir(key).is_err(), "{:?}", key);
+        assert!(cache::remove(key).is_err(), "{:?}", key);
+        assert!(cache::entry(key).is_none(), "{:?}", key);
+    }
+}
+
+#[test]
+fn finds_variables_of_cached_files_only() {
+    let cached = files_dir().join("abc123").join("file.json");
+    assert_eq!(variables_path(&cached), Some(files_dir().join("abc123").join("variables.json")));
+    assert_eq!(variables_path(Path::new("demo.json")), None);
+    assert_eq!(variables_path(Path::new("/tmp/designs/file.json")), None);
+    assert_eq!(variables_path(&files_dir().join("abc123").join("other.json")), None);
+}
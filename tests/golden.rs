@@ -0,0 +1,113 @@
+//! Golden tests for the generated JSX of the instances in `demo.json`.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to rewrite the files in `tests/golden`.
+
+mod support;
+
+use std::{fs, path::Path, sync::OnceLock};
+
+use figma2sprout::{
+    gen::{
+        component_generator::Generators, context::GenContext, node_util::convert_json_to_figma,
+        query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+    },
+    schema::File,
+};
+use support::snapshot::check_snapshot;
+
+/// `demo.json` with its definitions and tokens, parsed once for all tests.
+struct Demo {
+    file: File,
+    definitions: PropertyDefinitions,
+    tokens: TokenResolver,
+}
+fn demo() -> &'static Demo {
+    static DEMO: OnceLock<Demo> = OnceLock::new();
+    DEMO.get_or_init(|| {
+        let file = convert_json_to_figma(fs::read_to_string("demo.json").unwrap()).unwrap();
+        Demo {
+            definitions: PropertyDefinitions::from_file(&file),
+            tokens: TokenResolver::from_file(&file),
+            file,
+        }
+    })
+}
+
+fn assert_golden(name: &str, node_id: &str) {
+    let Demo { file, definitions, tokens } = demo();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(file, &generators, definitions, tokens);
+
+    let node = find_by_id(&file.document, node_id).unwrap_or_else(|| panic!("no node {node_id}"));
+    let component = generators
+        .gen_component(node, &ctx)
+        .unwrap_or_else(|| panic!("no generator for {node_id}"));
+    assert_eq!(component.name(), name.split('_').next().unwrap());
+    let actual = component.to_jsx().render();
+
+    let path = Path::new("tests/golden").join(format!("{name}.jsx"));
+    if let Err(failure) = check_snapshot(&path, &actual) {
+        panic!("{}\nrun with UPDATE_SNAPSHOTS=1 to update it", failure);
+    }
+}
+
+#[test]
+fn button_primary() {
+    assert_golden("Button_primary", "I2:982;21392:6615;21392:5913;6643:52217");
+}
+
+#[test]
+fn button_quiet() {
+    assert_golden("Button_quiet", "I2:982;21392:6615;21392:5913;21667:11039");
+}
+
+#[test]
+fn icon_button_quiet() {
+    assert_golden("IconButton_quiet", "I2:2336;20326:6282");
+}
+
+#[test]
+fn button_default() {
+    assert_golden("Button_default", "I2:2336;20346:1884");
+}
+
+#[test]
+fn badge_dot() {
+    assert_golden("Badge_dot", "I2:2336;20326:6282;22094:50968;18970:7244");
+}
+
+#[test]
+fn badge_alphanumeric() {
+    assert_golden("Badge_alphanumeric", "I2:2642;4081:10003;20598:56420");
+}
+
+#[test]
+fn tag_small() {
+    assert_golden("Tag_small", "I2:2642;4081:10003");
+}
+
+#[test]
+fn tag_group_skips_hidden_tags() {
+    assert_golden("TagGroup_default", "2:2642");
+}
+
+#[test]
+fn modal_small() {
+    assert_golden("Modal_small", "2:982");
+}
+
+#[test]
+fn avatar_image() {
+    assert_golden("Avatar_image", "I2:2642;4081:10003;19846:75188");
+}
+
+#[test]
+fn link_external() {
+    assert_golden("Link_external", "I2:2336;22073:59109");
+}
+
+#[test]
+fn input_button_icon() {
+    assert_golden("TextField_input_button", "I2:2642;4081:10003;19735:15197");
+}
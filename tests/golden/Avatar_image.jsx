@@ -0,0 +1 @@
+<Avatar size="xsmall" src="images/2e5f78f44b20ef67ab74659b2552d4e86c81968d" />
@@ -0,0 +1 @@
+<Badge color="info" badgeContent="23" />
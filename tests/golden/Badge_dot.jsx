@@ -0,0 +1 @@
+<Badge color="info" variant="dot" />
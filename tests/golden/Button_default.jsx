@@ -0,0 +1,3 @@
+<Button>
+  Action
+</Button>
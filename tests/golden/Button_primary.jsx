@@ -0,0 +1,3 @@
+<Button variant="primary">
+  Confirm
+</Button>
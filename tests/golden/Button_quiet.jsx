@@ -0,0 +1,3 @@
+<Button variant="quiet">
+  Cancel
+</Button>
@@ -0,0 +1,3 @@
+<IconButton variant="quiet" aria-label="Cross">
+  <Cross />
+</IconButton>
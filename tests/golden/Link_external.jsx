@@ -0,0 +1,3 @@
+<Link href="#" target="_blank" rel="noopener noreferrer">
+  Link
+</Link>
@@ -0,0 +1,25 @@
+<Modal open size="small">
+  <ModalHeader onClose={() => {}}>
+    Dialog Title
+  </ModalHeader>
+  <ModalContent>
+    <Alert severity="error" onClose={() => {}}>
+      Alert content
+    </Alert>
+    <FormControlLabel control={<Checkbox />} label="Label" />
+    <TagGroup>
+      <Tag label="Tag" size="small" color="neutral" />
+      <Tag label="Tag" size="small" color="neutral" />
+      <Tag label="Tag" size="small" color="neutral" />
+      <Tag label="Tag" size="small" color="neutral" />
+    </TagGroup>
+  </ModalContent>
+  <ModalActions>
+    <Button variant="quiet">
+      Cancel
+    </Button>
+    <Button variant="primary">
+      Confirm
+    </Button>
+  </ModalActions>
+</Modal>
@@ -0,0 +1,6 @@
+<TagGroup>
+  <Tag label="Tag" size="small" color="neutral" />
+  <Tag label="Tag" size="small" color="neutral" />
+  <Tag label="Tag" size="small" color="neutral" />
+  <Tag label="Tag" size="small" color="neutral" />
+</TagGroup>
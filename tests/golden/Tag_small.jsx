@@ -0,0 +1 @@
+<Tag label="Tag" size="small" color="neutral" />
@@ -0,0 +1 @@
+<TextField size="xsmall" InputProps={{ endAdornment: <InputAdornment position="end"><IconButton aria-label="ClosingX16"><ClosingX16 /></IconButton></InputAdornment> }} />
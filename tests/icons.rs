@@ -0,0 +1,9 @@
+use figma2sprout::gen::icons::icon_component;
+
+#[test]
+fn icon_names() {
+    assert_eq!(icon_component("icons/outline/download").as_deref(), Some("Download"));
+    assert_eq!(icon_component("icons/outline/closing_X_16").as_deref(), Some("ClosingX16"));
+    assert_eq!(icon_component("icons/"), None);
+    assert_eq!(icon_component("Button"), None);
+}
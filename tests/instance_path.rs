@@ -0,0 +1,36 @@
+use std::fs;
+
+use figma2sprout::{
+    gen::node_util::{convert_json_to_figma, exposed_instances, find_instance_node},
+    schema::InstancePath,
+};
+
+#[test]
+fn parses_and_formats_instance_ids() {
+    let path: InstancePath = "I2:982;21392:6615;21392:5912".parse().unwrap();
+    assert_eq!(path.segments(), ["2:982", "21392:6615", "21392:5912"]);
+    assert_eq!(path.root(), "2:982");
+    assert_eq!(path.parent().unwrap().to_string(), "I2:982;21392:6615");
+    assert_eq!(path.prefix(1).unwrap().to_string(), "2:982");
+    assert_eq!(path.to_string(), "I2:982;21392:6615;21392:5912");
+
+    let plain: InstancePath = "2:982".parse().unwrap();
+    assert!(!plain.is_nested());
+    assert!(path.starts_with(&plain));
+    assert!("I2:982;;1:2".parse::<InstancePath>().is_err());
+}
+
+#[test]
+fn resolves_nested_and_exposed_instances() {
+    let file = convert_json_to_figma(fs::read_to_string("demo.json").unwrap()).unwrap();
+    let path = "I2:982;21392:6615;21392:5913;6643:52217".parse().unwrap();
+    let button = find_instance_node(&file.document, &path).unwrap();
+    assert_eq!(button.name, "Button");
+
+    let modal = find_instance_node(&file.document, &"2:982".parse().unwrap()).unwrap();
+    let exposed: Vec<&str> = exposed_instances(modal)
+        .iter()
+        .map(|instance| instance.name.as_str())
+        .collect();
+    assert_eq!(exposed, ["_Modal", "_ModalHeader", "_ModalActions"]);
+}
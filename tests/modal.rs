@@ -0,0 +1,79 @@
+use figma2sprout::gen::{
+    component_generator::Generators, context::GenContext, node_util::convert_json_to_figma,
+    query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+};
+use serde_json::json;
+
+#[test]
+fn leaves_out_slots_hidden_by_properties() {
+    let badge = |id: &str, text: &str| {
+        json!({
+            "id": id,
+            "name": "Badge",
+            "type": "INSTANCE",
+            "componentProperties": { "Text#1:1": { "type": "TEXT", "value": text } }
+        })
+    };
+    let file = json!({
+        "name": "Modals",
+        "version": "1",
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{
+                "id": "1:1",
+                "name": "Modal",
+                "type": "INSTANCE",
+                "componentProperties": {
+                    "Show header#2:1": { "type": "BOOLEAN", "value": false }
+                },
+                "children": [{
+                    "id": "1:2",
+                    "name": "_Modal",
+                    "type": "INSTANCE",
+                    "componentProperties": {
+                        "Show extra#3:1": { "type": "BOOLEAN", "value": false }
+                    },
+                    "children": [
+                        {
+                            "id": "1:3",
+                            "name": "_ModalHeader",
+                            "type": "INSTANCE",
+                            "componentPropertyReferences": { "visible": "Show header#2:1" }
+                        },
+                        {
+                            "id": "1:4",
+                            "name": "Content",
+                            "type": "FRAME",
+                            "children": [badge("1:5", "shown")]
+                        },
+                        {
+                            "id": "1:6",
+                            "name": "Extra",
+                            "type": "FRAME",
+                            "componentPropertyReferences": { "visible": "Show extra#3:1" },
+                            "children": [badge("1:7", "hidden")]
+                        }
+                    ]
+                }]
+            }]
+        }
+    });
+    let file = convert_json_to_figma(file.to_string()).unwrap();
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+
+    let modal = find_by_id(&file.document, "1:1").unwrap();
+    let jsx = generators
+        .gen_component(modal, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    assert!(!jsx.contains("ModalHeader"), "{}", jsx);
+    assert!(jsx.contains("badgeContent=\"shown\""), "{}", jsx);
+    assert!(!jsx.contains("hidden"), "{}", jsx);
+}
@@ -0,0 +1,32 @@
+use std::{fs, sync::Arc};
+
+use figma2sprout::{
+    gen::node_util::{convert_json_to_figma, find_figma_node, NodeIndex},
+    schema::{NodeId, NodePath},
+};
+
+#[test]
+fn paths_compare_whole_ids() {
+    let parent = NodePath::from(vec![NodeId::from("0:0"), NodeId::from("1:2")]);
+    let sibling = NodePath::from(vec![NodeId::from("0:0"), NodeId::from("1:23")]);
+    assert!(!sibling.starts_with(&parent));
+    assert!(parent.child("3:4").starts_with(&parent));
+    assert_eq!(parent.child("3:4").to_string(), "0:0|1:2|3:4");
+}
+
+#[test]
+fn index_finds_every_node() {
+    let file = convert_json_to_figma(fs::read_to_string("demo.json").unwrap()).unwrap();
+    let index = NodeIndex::new(Arc::new(file));
+
+    let id = NodeId::from("I2:982;21392:6615;21392:5913;6643:52217");
+    let button = index.get(&id).unwrap();
+    assert_eq!(button.name, "Button");
+
+    let path = index.path(&id).unwrap();
+    assert_eq!(path.last(), Some(&id));
+    let found = find_figma_node(index.file(), &path).unwrap();
+    assert_eq!(found.id, button.id);
+
+    assert!(index.get(&NodeId::from("404:404")).is_none());
+}
@@ -0,0 +1,136 @@
+use figma2sprout::{
+    gen::{
+        component_generator::Generators,
+        context::GenContext,
+        customization::{customization_report, customizations, Customization},
+        node_util::{convert_json_to_figma, is_shown, main_component_id, shown_characters},
+        query::find_by_id,
+        tokens::TokenResolver,
+        validation::PropertyDefinitions,
+    },
+    schema::Node,
+};
+use serde_json::json;
+
+fn instance() -> Node {
+    serde_json::from_value(json!({
+        "id": "1:1",
+        "name": "Button",
+        "type": "INSTANCE",
+        "componentId": "7:1",
+        "componentProperties": {
+            "Label#1:10": { "type": "TEXT", "value": "Save" },
+            "Icon#1:11": { "type": "BOOLEAN", "value": false },
+            "Icon instance#1:12": { "type": "INSTANCE_SWAP", "value": "8:2" }
+        },
+        "overrides": [
+            { "id": "1:1", "overriddenFields": ["componentProperties", "fills"] },
+            { "id": "1:2", "overriddenFields": ["characters", "fontSize"] },
+            { "id": "1:3", "overriddenFields": ["visible", "componentId"] },
+            { "id": "1:5", "overriddenFields": ["characters", "name"] }
+        ],
+        "children": [
+            {
+                "id": "1:2",
+                "name": "Label",
+                "type": "TEXT",
+                "characters": "Button",
+                "componentPropertyReferences": { "characters": "Label#1:10" }
+            },
+            {
+                "id": "1:3",
+                "name": "Icon",
+                "type": "INSTANCE",
+                "componentId": "8:1",
+                "componentPropertyReferences": {
+                    "visible": "Icon#1:11",
+                    "mainComponent": "Icon instance#1:12"
+                }
+            },
+            { "id": "1:4", "name": "Hidden", "type": "TEXT", "characters": "x", "visible": false },
+            { "id": "1:5", "name": "Hint", "type": "TEXT", "characters": "Custom" }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn resolves_referenced_properties_of_layers() {
+    let instance = instance();
+    let layer = |id: &str| find_by_id(&instance, id).unwrap();
+
+    assert_eq!(shown_characters(&instance, layer("1:2")).as_deref(), Some("Save"));
+    assert_eq!(shown_characters(&instance, layer("1:5")).as_deref(), Some("Custom"));
+    assert_eq!(main_component_id(&instance, layer("1:3")).as_deref(), Some("8:2"));
+
+    // bound to the `Icon` boolean, which is off
+    assert!(!is_shown(&instance, layer("1:3")));
+    assert!(!is_shown(&instance, layer("1:4")));
+    assert!(is_shown(&instance, layer("1:2")));
+    assert!(is_shown(&instance, layer("1:5")));
+}
+
+#[test]
+fn reports_overrides_the_props_do_not_express() {
+    let instance = instance();
+    let customization = |id: &str, name: &str, fields: &[&str]| Customization {
+        layer_id: id.to_string(),
+        layer_name: name.to_string(),
+        fields: fields.iter().map(|field| field.to_string()).collect(),
+    };
+    assert_eq!(
+        customizations(&instance),
+        [
+            customization("1:1", "Button", &["fills"]),
+            customization("1:2", "Label", &["fontSize"]),
+            customization("1:5", "Hint", &["characters"]),
+        ]
+    );
+
+    let messages: Vec<String> = customization_report(&instance)
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "the instance overrides `fills` which the generated props don't express",
+            "layer `Label` (1:2) overrides `fontSize` which the generated props don't express",
+            "layer `Hint` (1:5) overrides `characters` which the generated props don't express",
+        ]
+    );
+
+    let plain: Node =
+        serde_json::from_value(json!({ "id": "2:1", "name": "Button", "type": "INSTANCE" }))
+            .unwrap();
+    assert_eq!(customization_report(&plain), vec![]);
+}
+
+#[test]
+fn frames_are_not_checked_like_instances() {
+    let file = json!({
+        "name": "Frames",
+        "version": "1",
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{
+                "id": "1:1",
+                "name": "Card",
+                "type": "FRAME",
+                "componentId": "7:1",
+                "overrides": [{ "id": "1:1", "overriddenFields": ["fills"] }]
+            }]
+        }
+    });
+    let file = convert_json_to_figma(file.to_string()).unwrap();
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+    let frame = find_by_id(&file.document, "1:1").unwrap();
+    assert!(generators.gen_component(frame, &ctx).is_some());
+    assert_eq!(ctx.take_diagnostics(), vec![]);
+}
@@ -0,0 +1,53 @@
+use std::{borrow::Cow, fs, io::Cursor};
+
+use figma2sprout::schema::parser::{parse_outline, parse_reader, read_figma_file, LazyFile};
+
+#[test]
+fn parses_from_a_reader() {
+    let json = fs::read_to_string("demo.json").unwrap();
+    let file = parse_reader(Cursor::new(json.as_bytes())).unwrap();
+    assert_eq!(file.name, read_figma_file("demo.json").unwrap().name);
+}
+
+#[test]
+fn outline_borrows_from_the_input() {
+    let json = fs::read_to_string("demo.json").unwrap();
+    let outline = parse_outline(&json).unwrap();
+    assert!(matches!(outline.document.id, Cow::Borrowed(_)));
+    assert_eq!(outline.document.node_type, "DOCUMENT");
+    assert!(!outline.document.children.is_empty());
+}
+
+#[test]
+fn lazy_file_loads_open_pages_only() {
+    let json = fs::read_to_string("demo.json").unwrap();
+    let lazy = LazyFile::from_reader(Cursor::new(json.as_bytes())).unwrap();
+    let page = &lazy.pages()[0];
+
+    let closed = lazy.to_file(&[]).unwrap();
+    let closed_page = &closed.document.children.as_ref().unwrap()[0];
+    assert_eq!(closed_page.id, page.id);
+    assert!(closed_page.children.is_none());
+
+    let open = lazy.to_file(&[&page.id]).unwrap();
+    assert!(open.document.children.as_ref().unwrap()[0]
+        .children
+        .is_some());
+    assert_eq!(open.components.len(), closed.components.len());
+}
+
+#[test]
+fn lazy_file_reads_a_path() {
+    let lazy = LazyFile::read("demo.json").unwrap();
+    let file = read_figma_file("demo.json").unwrap();
+    let pages: Vec<&str> = lazy.pages().iter().map(|page| page.id.as_str()).collect();
+    let expected: Vec<&str> = file
+        .document
+        .children
+        .iter()
+        .flatten()
+        .map(|page| page.id.as_str())
+        .collect();
+    assert_eq!(pages, expected);
+    assert!(LazyFile::read("missing.json").is_err());
+}
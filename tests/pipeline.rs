@@ -0,0 +1,129 @@
+//! The whole pipeline, from fetching a file to generating code, against the
+//! recorded API responses in `tests/fixtures/api`.
+
+mod support;
+
+use figma2sprout::{
+    cache::{self, CacheStatus},
+    figma_api::{ApiConfig, FigmaApi},
+    files::variables_path,
+    gen::{
+        component_generator::Generators, context::GenContext, node_util::NodeIndex,
+        query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+        variables::convert_json_to_local_variables,
+    },
+    schema::{parser::read_figma_file, FileNodesResponse, NodeId},
+    ui::tree::{parse_file_to_tree, GenStatus},
+};
+use std::{env, fs, sync::Arc, time::Duration};
+use support::{MockServer, FIXTURES};
+
+fn api(server: &MockServer) -> FigmaApi {
+    let config = ApiConfig {
+        root: server.url(),
+        max_retries: 0,
+        initial_backoff: Duration::from_millis(10),
+        ..ApiConfig::default()
+    };
+    FigmaApi::with_config("token", config).unwrap()
+}
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+fn generate(file: &figma2sprout::schema::File, id: &str) -> String {
+    let definitions = PropertyDefinitions::from_file(file);
+    let tokens = TokenResolver::from_file(file);
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(file, &generators, &definitions, &tokens);
+    let node = find_by_id(&file.document, id).unwrap();
+    generators
+        .gen_component(node, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render()
+}
+
+// The cache directory comes from the environment, it is the only test using it.
+#[test]
+fn fetch_parse_tree_and_generate() {
+    let dir = env::temp_dir().join(format!("figma2sprout-pipeline-{}", std::process::id()));
+    env::set_var("XDG_CACHE_HOME", &dir);
+    let server = MockServer::replay(FIXTURES);
+    let api = api(&server);
+
+    let (path, status) = block_on(cache::fetch_file(&api, "demo")).unwrap();
+    assert_eq!(status, CacheStatus::New);
+    let variables = fs::read_to_string(variables_path(&path).unwrap()).unwrap();
+    assert_eq!(
+        convert_json_to_local_variables(&variables)
+            .unwrap()
+            .variables
+            .len(),
+        2
+    );
+    assert_eq!(block_on(cache::fetch_file(&api, "demo")).unwrap().1, CacheStatus::Fresh);
+
+    let file = Arc::new(read_figma_file(&path).unwrap());
+    assert_eq!(file.version, "6710852001");
+    let mut tree = block_on(parse_file_to_tree(file.clone())).unwrap();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].name, "Page 1");
+
+    let index = NodeIndex::new(file.clone());
+    let checkbox = index.get(&NodeId::from("2:2440")).unwrap();
+    assert_eq!(checkbox.name, "Checkbox");
+    // the tree and the index agree on where the node is
+    let path = index.path(&NodeId::from("2:2440")).unwrap();
+    assert_eq!(tree[0].find_child(&path).unwrap().name, "Checkbox");
+    assert!(generate(&file, "2:2440").contains("<Checkbox"));
+
+    let mut generators = Generators::new();
+    generators.register_generators();
+    tree[0].update_status(&index, &generators);
+    assert_eq!(
+        tree[0].find_child(&path).unwrap().status,
+        GenStatus::Generated { component: "Checkbox".to_string(), diagnostics: None }
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn nodes_generate_like_the_whole_file() {
+    let server = MockServer::replay(FIXTURES);
+    let json = block_on(api(&server).fetch_nodes("demo", &["2:2440"])).unwrap();
+    assert_eq!(server.requests(), vec!["/files/demo/nodes?ids=2%3A2440"]);
+    let response: FileNodesResponse = serde_json::from_str(&json).unwrap();
+    let file = response.into_file("2:2440").unwrap();
+
+    let whole = read_figma_file("demo.json").unwrap();
+    assert_eq!(generate(&file, "2:2440"), generate(&whole, "2:2440"));
+}
+
+#[test]
+fn images_without_a_render_are_none() {
+    let server = MockServer::replay(FIXTURES);
+    let images =
+        block_on(api(&server).fetch_images("demo", &["2:2440", "2:2336"], "png", 1.0)).unwrap();
+    assert!(images["2:2440"]
+        .as_deref()
+        .is_some_and(|url| url.starts_with("https://")));
+    assert_eq!(images["2:2336"], None);
+}
+
+#[test]
+fn error_responses_fail_with_their_status() {
+    let server = MockServer::replay(FIXTURES);
+    let api = api(&server);
+    for (key, status, message) in [
+        ("forbidden", 403, "Invalid token"),
+        ("missing", 404, "Not found"),
+        ("limited", 429, "Rate limit exceeded"),
+        ("broken", 500, "Internal server error"),
+    ] {
+        let error = block_on(api.fetch_figma_file(key)).unwrap_err();
+        assert_eq!(error.status().map(|status| status.as_u16()), Some(status));
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+}
@@ -0,0 +1,132 @@
+use std::fs;
+
+use figma2sprout::{
+    gen::{
+        node_util::{convert_json_to_figma, NodeIndex},
+        query::{
+            descendants, descendants_breadth_first, find_by_component_set, find_by_id,
+            find_by_name, find_by_type, glob_match, Selector,
+        },
+    },
+    schema::{File, NodeId, NodeType},
+};
+use std::sync::Arc;
+
+fn demo() -> File {
+    convert_json_to_figma(fs::read_to_string("demo.json").unwrap()).unwrap()
+}
+
+#[test]
+fn globs() {
+    assert!(glob_match("icons/*", "icons/outline/star"));
+    assert!(glob_match("*Button", "IconButton"));
+    assert!(glob_match("Tag?roup", "TagGroup"));
+    assert!(!glob_match("Tag", "TagGroup"));
+}
+
+#[test]
+fn finds_nodes() {
+    let file = demo();
+    let root = &file.document;
+    let modal = find_by_id(root, "2:982").unwrap();
+    assert_eq!(modal.name, "Modal");
+    assert!(find_by_type(modal, NodeType::Instance)
+        .iter()
+        .any(|node| node.name == "TagGroup"));
+    assert_eq!(find_by_name(root, "TagGroup").len(), 2);
+    assert!(find_by_name(root, "icons/outline/*").len() > 10);
+    let tags = find_by_component_set(&file, root, "Tag");
+    assert!(!tags.is_empty() && tags.iter().all(|tag| tag.name == "Tag"));
+}
+
+#[test]
+fn walks_the_tree() {
+    let file = Arc::new(demo());
+    let root = &file.document;
+    let depth_first: Vec<&str> = descendants(root).map(|node| node.id.as_str()).collect();
+    let breadth_first: Vec<&str> = descendants_breadth_first(root)
+        .map(|node| node.id.as_str())
+        .collect();
+    assert_eq!(depth_first.len(), breadth_first.len());
+    assert_eq!(depth_first[0], breadth_first[0]);
+
+    let index = NodeIndex::new(file.clone());
+    let id = NodeId::new("I2:982;21392:6615");
+    let ancestors: Vec<&str> = index
+        .ancestors(&id)
+        .iter()
+        .map(|node| node.id.as_str())
+        .collect();
+    assert_eq!(ancestors[0], "2:982");
+    assert_eq!(*ancestors.last().unwrap(), root.id);
+    assert_eq!(index.parent(&id).unwrap().id, "2:982");
+}
+
+#[test]
+fn selects_nodes() {
+    let file = demo();
+    let root = &file.document;
+    let selector = Selector::parse(r#"INSTANCE[name="TagGroup"] > INSTANCE[name="Tag"]"#).unwrap();
+    assert_eq!(selector.select(&file, root).len(), 18);
+
+    let selector =
+        Selector::parse(r#"INSTANCE[componentSet="Modal"] TEXT[name^="Dialog"]"#).unwrap();
+    let texts: Vec<&str> = selector
+        .select(&file, root)
+        .iter()
+        .map(|node| node.name.as_str())
+        .collect();
+    assert_eq!(texts, ["Dialog Title", "Dialog Description"]);
+
+    let selector = Selector::parse(r#"*[name~="icons/*/cross"]"#).unwrap();
+    assert!(!selector.select(&file, root).is_empty());
+
+    assert!(Selector::parse("FRAME >").is_err());
+    assert!(Selector::parse("FRAME[name").is_err());
+}
+
+#[test]
+fn parses_every_operator() {
+    let file = convert_json_to_figma(
+        r#"{
+          "name": "Names",
+          "version": "1",
+          "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [
+              { "id": "1:1", "name": "Checkbox", "type": "INSTANCE" },
+              { "id": "1:2", "name": "Checkbox label", "type": "TEXT" },
+              { "id": "1:3", "name": "Big checkbox", "type": "FRAME" }
+            ]
+          }
+        }"#
+        .to_string(),
+    )
+    .unwrap();
+    let select = |selector: &str| -> Vec<String> {
+        Selector::parse(selector)
+            .unwrap_or_else(|e| panic!("{}: {}", selector, e))
+            .select(&file, &file.document)
+            .iter()
+            .map(|node| node.id.clone())
+            .collect()
+    };
+    assert_eq!(select(r#"[name="Checkbox"]"#), ["1:1"]);
+    assert_eq!(select(r#"[name^="Check"]"#), ["1:1", "1:2"]);
+    assert_eq!(select(r#"[name*="box"]"#), ["1:1", "1:2", "1:3"]);
+    assert_eq!(select(r#"[name~="*label"]"#), ["1:2"]);
+    assert_eq!(select(r#"*[ name *= 'label' ]"#), ["1:2"]);
+    assert_eq!(select("[name*=box]"), ["1:1", "1:2", "1:3"]);
+    assert_eq!(select("[name~=Check*]"), ["1:1", "1:2"]);
+    assert_eq!(select(r#"TEXT[id^="1:"]"#), ["1:2"]);
+
+    assert!(Selector::parse(r#"[="x"]"#).is_err());
+    assert!(Selector::parse(r#"[name!="x"]"#).is_err());
+    assert!(Selector::parse(r#"[name*"x"]"#).is_err());
+    assert!(Selector::parse("FRAMEE").is_err());
+    // a misspelled attribute would otherwise match nothing
+    assert!(Selector::parse(r#"INSTANCE[nmae="Checkbox"]"#).is_err());
+    assert!(Selector::parse(r#"[ID="2:982"]"#).is_err());
+}
@@ -0,0 +1,41 @@
+use figma2sprout::settings::{load_token, save_token, Settings, TOKEN_ENV};
+use std::env;
+
+// The config directory comes from the environment, so everything runs in one test.
+#[test]
+fn settings_and_token_are_saved_in_the_config_dir() {
+    let dir = env::temp_dir().join(format!("figma2sprout-settings-{}", std::process::id()));
+    env::set_var("XDG_CONFIG_HOME", &dir);
+    env::remove_var(TOKEN_ENV);
+
+    assert_eq!(Settings::load(), Settings::default());
+    let mut settings = Settings { file_id: "abc123".to_string(), ..Default::default() };
+    settings.recent_files.push("demo.json");
+    settings.window.width = 640.0;
+    settings.window.tree_ratio = 0.25;
+    settings.generator.diagnostics = false;
+    settings.save().unwrap();
+    assert_eq!(Settings::load(), settings);
+
+    assert_eq!(load_token(), None);
+    save_token("secret").unwrap();
+    assert_eq!(load_token().as_deref(), Some("secret"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(dir.join("figma2sprout/token"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    assert!(!std::fs::read_to_string(Settings::path())
+        .unwrap()
+        .contains("secret"));
+
+    env::set_var(TOKEN_ENV, "from-env");
+    assert_eq!(load_token().as_deref(), Some("from-env"));
+    env::remove_var(TOKEN_ENV);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,110 @@
+//! Snapshots of the code generated for every instance of the fixture files:
+//! `demo.json` and the files in `tests/fixtures/files`. Each fixture has a
+//! directory in `tests/snapshots` with a `.snap` file per component, holding
+//! the generated code and the diagnostics of its instances.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to rewrite the snapshots.
+
+mod support;
+
+use figma2sprout::{
+    gen::{
+        component_generator::Generators, context::GenContext, query::descendants,
+        tokens::TokenResolver, validation::PropertyDefinitions,
+    },
+    schema::{parser::read_figma_file, File, NodeType},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+use support::snapshot::{check_snapshot, update_mode};
+
+const FIXTURES: &str = "tests/fixtures/files";
+const SNAPSHOTS: &str = "tests/snapshots";
+
+fn fixture_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(FIXTURES)
+        .map(|files| {
+            files
+                .flatten()
+                .map(|file| file.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files.insert(0, PathBuf::from("demo.json"));
+    files
+}
+
+/// The snapshot of each component generated from the instances of `file`.
+fn snapshots(file: &File) -> BTreeMap<String, String> {
+    let definitions = PropertyDefinitions::from_file(file);
+    let tokens = TokenResolver::from_file(file);
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(file, &generators, &definitions, &tokens);
+
+    let mut snapshots: BTreeMap<String, String> = BTreeMap::new();
+    for node in descendants(&file.document).filter(|node| node.r#type == NodeType::Instance) {
+        let component = generators.gen_component(node, &ctx);
+        let diagnostics = ctx.take_diagnostics();
+        let Some(component) = component else { continue };
+        let snapshot = snapshots.entry(component.name()).or_default();
+        if !snapshot.is_empty() {
+            snapshot.push('\n');
+        }
+        writeln!(snapshot, "// {} {}", node.id, node.name).unwrap();
+        snapshot.push_str(&component.to_jsx().render());
+        for diagnostic in diagnostics {
+            writeln!(snapshot, "// {}", diagnostic).unwrap();
+        }
+    }
+    snapshots
+}
+
+/// Checks the snapshots of the fixture `path`, and returns the failures.
+fn check_fixture(path: &Path) -> Vec<String> {
+    let file = read_figma_file(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let dir = Path::new(SNAPSHOTS).join(path.file_stem().unwrap());
+    let snapshots = snapshots(&file);
+    let mut failures: Vec<String> = snapshots
+        .iter()
+        .filter_map(|(name, snapshot)| {
+            check_snapshot(&dir.join(format!("{}.snap", name)), snapshot).err()
+        })
+        .collect();
+    // snapshots of components the fixture no longer generates
+    let files = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|file| file.path());
+    for stale in files.filter(|path| path.extension().is_some_and(|ext| ext == "snap")) {
+        let name = stale.file_stem().unwrap().to_string_lossy().into_owned();
+        if !snapshots.contains_key(&name) {
+            if update_mode() {
+                fs::remove_file(&stale).unwrap();
+            } else {
+                failures.push(format!("{} is stale", stale.display()));
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn generated_code_matches_snapshots() {
+    let failures: Vec<String> = fixture_files()
+        .iter()
+        .flat_map(|path| check_fixture(path))
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{}\nrun with UPDATE_SNAPSHOTS=1 to update the snapshots",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,195 @@
+use figma2sprout::{
+    gen::{
+        jsx::PropValue,
+        style::{image_fill_diagnostics, node_sx, typography_sx},
+        tokens::TokenResolver,
+    },
+    schema::{Node, TypeStyle},
+};
+use serde_json::{json, Value};
+
+fn node(fields: Value) -> Node {
+    let mut node = json!({ "id": "1:1", "name": "Frame", "type": "FRAME" });
+    node.as_object_mut()
+        .unwrap()
+        .extend(fields.as_object().unwrap().clone());
+    serde_json::from_value(node).unwrap()
+}
+fn sx(node: &Node) -> Vec<(String, String)> {
+    let sx = node_sx(node, &TokenResolver::new());
+    let keys = [
+        "display",
+        "flexDirection",
+        "gap",
+        "p",
+        "pt",
+        "pl",
+        "backgroundColor",
+        "background",
+        "border",
+        "borderTop",
+        "borderLeft",
+        "outline",
+        "borderRadius",
+        "boxShadow",
+        "filter",
+        "opacity",
+        "mixBlendMode",
+    ];
+    keys.iter()
+        .filter_map(|key| {
+            sx.get(key)
+                .map(|value| (key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+fn entries(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn lays_out_auto_layout_frames() {
+    let frame = node(json!({
+        "layoutMode": "VERTICAL",
+        "itemSpacing": 8,
+        "paddingTop": 16, "paddingRight": 16, "paddingBottom": 16, "paddingLeft": 16,
+        "fills": [{ "type": "SOLID", "color": { "r": 1, "g": 0, "b": 0, "a": 1 } }],
+        "cornerRadius": 4
+    }));
+    assert_eq!(
+        sx(&frame),
+        entries(&[
+            ("display", "'flex'"),
+            ("flexDirection", "'column'"),
+            ("gap", "'8px'"),
+            ("p", "'16px'"),
+            ("backgroundColor", "'#ff0000'"),
+            ("borderRadius", "'4px'"),
+        ])
+    );
+
+    let row = node(json!({ "layoutMode": "HORIZONTAL", "paddingTop": 4, "paddingLeft": 8 }));
+    assert_eq!(sx(&row), entries(&[("display", "'flex'"), ("pt", "'4px'"), ("pl", "'8px'")]));
+}
+
+#[test]
+fn layers_fills_and_refers_to_images_by_placeholder() {
+    let frame = node(json!({
+        "fills": [
+            { "type": "IMAGE", "imageRef": "abc123", "scaleMode": "FIT" },
+            {
+                "type": "GRADIENT_LINEAR",
+                "gradientHandlePositions": [{ "x": 0, "y": 0 }, { "x": 0, "y": 1 }, { "x": 1, "y": 0 }],
+                "gradientStops": [
+                    { "position": 0, "color": { "r": 0, "g": 0, "b": 0, "a": 1 } },
+                    { "position": 1, "color": { "r": 1, "g": 1, "b": 1, "a": 1 } }
+                ]
+            },
+            { "type": "SOLID", "visible": false, "color": { "r": 1, "g": 0, "b": 0, "a": 1 } }
+        ]
+    }));
+    assert_eq!(
+        sx(&frame),
+        entries(&[(
+            "background",
+            "'linear-gradient(180deg, #000000 0%, #ffffff 100%), url(\"images/abc123\") center / contain'"
+        )])
+    );
+    let diagnostics = image_fill_diagnostics(&frame);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("images/abc123"));
+}
+
+#[test]
+fn converts_strokes_effects_and_compositing() {
+    let frame = node(json!({
+        "strokes": [{ "type": "SOLID", "color": { "r": 0, "g": 0, "b": 1, "a": 1 } }],
+        "strokeWeight": 2,
+        "strokeDashes": [4, 2],
+        "rectangleCornerRadii": [4, 4, 0, 0],
+        "effects": [
+            {
+                "type": "DROP_SHADOW",
+                "color": { "r": 0, "g": 0, "b": 0, "a": 1 },
+                "offset": { "x": 0, "y": 1 },
+                "radius": 2
+            },
+            { "type": "LAYER_BLUR", "radius": 8 },
+            { "type": "INNER_SHADOW", "visible": false, "radius": 2 }
+        ],
+        "opacity": 0.5,
+        "blendMode": "MULTIPLY"
+    }));
+    assert_eq!(
+        sx(&frame),
+        entries(&[
+            ("border", "'2px dashed #0000ff'"),
+            ("borderRadius", "'4px 4px 0px 0px'"),
+            ("boxShadow", "'0px 1px 2px 0px #000000'"),
+            ("filter", "'blur(4px)'"),
+            ("opacity", "0.5"),
+            ("mixBlendMode", "'multiply'"),
+        ])
+    );
+
+    let sides = node(json!({
+        "strokes": [{ "type": "SOLID", "color": { "r": 0, "g": 0, "b": 0, "a": 1 } }],
+        "individualStrokeWeights": { "top": 1, "right": 0, "bottom": 0, "left": 3 }
+    }));
+    assert_eq!(
+        sx(&sides),
+        entries(&[("borderTop", "'1px solid #000000'"), ("borderLeft", "'3px solid #000000'")])
+    );
+    let outside = node(json!({
+        "strokes": [{ "type": "SOLID", "color": { "r": 0, "g": 0, "b": 0, "a": 1 } }],
+        "strokeAlign": "OUTSIDE"
+    }));
+    assert_eq!(sx(&outside), entries(&[("outline", "'1px solid #000000'")]));
+}
+
+#[test]
+fn uses_tokens_of_bound_variables() {
+    let mut tokens = TokenResolver::new();
+    tokens.add_variable("V:1", "Light/Background/Subtle");
+    tokens.add_variable("V:2", "sprout/spacing/m");
+    let frame = node(json!({
+        "layoutMode": "HORIZONTAL",
+        "itemSpacing": 12,
+        "boundVariables": { "itemSpacing": { "type": "VARIABLE_ALIAS", "id": "V:2" } },
+        "fills": [{
+            "type": "SOLID",
+            "color": { "r": 1, "g": 1, "b": 1, "a": 1 },
+            "boundVariables": { "color": { "type": "VARIABLE_ALIAS", "id": "V:1" } }
+        }]
+    }));
+    let sx = node_sx(&frame, &tokens);
+    assert_eq!(sx.get("gap"), Some("'spacing.m'"));
+    assert_eq!(sx.get("backgroundColor"), Some("'background.subtle'"));
+}
+
+#[test]
+fn converts_font_attributes() {
+    let style: TypeStyle = serde_json::from_value(json!({
+        "fontSize": 14,
+        "fontWeight": 600,
+        "lineHeightPx": 20,
+        "letterSpacing": 0,
+        "italic": true,
+        "textDecoration": "UNDERLINE",
+        "textCase": "UPPER",
+        "fills": [{ "type": "SOLID", "color": { "r": 0, "g": 0, "b": 0, "a": 1 } }]
+    }))
+    .unwrap();
+    let sx = typography_sx(&style, &TokenResolver::new());
+    assert_eq!(
+        sx.to_prop(),
+        Some(PropValue::Expression(
+            "{ fontSize: '14px', fontWeight: 600, lineHeight: '20px', fontStyle: 'italic', \
+             textDecoration: 'underline', textTransform: 'uppercase', color: '#000000' }"
+                .to_string()
+        ))
+    );
+}
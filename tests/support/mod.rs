@@ -0,0 +1,184 @@
+//! Helpers of the integration tests: a local stand-in for the Figma API and
+//! snapshot files.
+#![allow(dead_code)]
+
+pub mod snapshot;
+
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+impl MockResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        MockResponse { status, headers: vec![], body: body.into() }
+    }
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// The recorded API responses, listed in its `routes.json`.
+pub const FIXTURES: &str = "tests/fixtures/api";
+
+/// A route of `routes.json`, answered with the contents of `file`.
+#[derive(Deserialize)]
+struct Fixture {
+    path: String,
+    #[serde(default = "ok")]
+    status: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    /// Relative to the fixtures directory.
+    file: String,
+}
+fn ok() -> u16 {
+    200
+}
+
+#[derive(Default)]
+struct State {
+    /// The response of each path, the query included.
+    routes: HashMap<String, MockResponse>,
+    /// Responses sent once, before the route of their path.
+    queued: HashMap<String, VecDeque<MockResponse>>,
+    requests: Vec<String>,
+}
+impl State {
+    /// The response to `path`, matched with its query first and without it
+    /// second.
+    fn respond(&mut self, path: &str) -> MockResponse {
+        let without_query = path.split('?').next().unwrap_or(path);
+        for path in [path, without_query] {
+            if let Some(response) = self.queued.get_mut(path).and_then(VecDeque::pop_front) {
+                return response;
+            }
+            if let Some(response) = self.routes.get(path) {
+                return response.clone();
+            }
+        }
+        MockResponse::new(404, r#"{"status":404}"#)
+    }
+}
+
+/// An HTTP server answering each path with a fixed response, 404 otherwise.
+pub struct MockServer {
+    port: u16,
+    state: Arc<Mutex<State>>,
+}
+impl MockServer {
+    pub fn start() -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let state = Arc::new(Mutex::new(State::default()));
+        let server_state = state.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = server_state.clone();
+                thread::spawn(move || handle(stream, &state));
+            }
+        });
+        MockServer { port, state }
+    }
+    /// A server replaying the fixtures in `dir`.
+    pub fn replay(dir: impl AsRef<Path>) -> MockServer {
+        let dir = dir.as_ref();
+        let routes = fs::read_to_string(dir.join("routes.json")).unwrap();
+        let fixtures: Vec<Fixture> = serde_json::from_str(&routes).unwrap();
+        let server = MockServer::start();
+        for fixture in fixtures {
+            let body = fs::read_to_string(dir.join(&fixture.file))
+                .unwrap_or_else(|e| panic!("{}: {}", fixture.file, e));
+            let mut response = MockResponse::new(fixture.status, body);
+            for (name, value) in &fixture.headers {
+                response = response.header(name, value);
+            }
+            server.route_response(&fixture.path, response);
+        }
+        server
+    }
+    /// The API root to send requests to, e.g. as `FIGMA_API_ROOT`.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/v1", self.port)
+    }
+    /// Answers `path`, relative to the API root, with `body`.
+    pub fn route(&self, path: &str, status: u16, body: impl Into<String>) {
+        self.route_response(path, MockResponse::new(status, body));
+    }
+    pub fn route_response(&self, path: &str, response: MockResponse) {
+        let path = format!("/v1{}", path);
+        self.state.lock().unwrap().routes.insert(path, response);
+    }
+    /// Answers the next request to `path` with `response`, before its route.
+    pub fn queue(&self, path: &str, response: MockResponse) {
+        let path = format!("/v1{}", path);
+        self.state
+            .lock()
+            .unwrap()
+            .queued
+            .entry(path)
+            .or_default()
+            .push_back(response);
+    }
+    /// The paths requested so far, relative to the API root.
+    pub fn requests(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .map(|path| path.trim_start_matches("/v1").to_string())
+            .collect()
+    }
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    // skip the headers, requests have no body
+    let mut line = String::new();
+    while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+        line.clear();
+    }
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("/")
+        .to_string();
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(path.clone());
+        state.respond(&path)
+    };
+    let headers: String = response
+        .headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}\r\n", name, value))
+        .collect();
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        headers,
+        response.body
+    );
+    let _ = (&stream).write_all(response.as_bytes());
+}
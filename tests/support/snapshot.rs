@@ -0,0 +1,78 @@
+//! Snapshot files: the expected output of a test, checked into the repo.
+//!
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to rewrite the snapshots instead.
+
+use std::{fs, path::Path};
+
+/// Lines kept around each change in a diff.
+const CONTEXT: usize = 3;
+
+pub fn update_mode() -> bool {
+    std::env::var_os("UPDATE_SNAPSHOTS").is_some()
+}
+
+/// Compares `actual` to the snapshot at `path`, or writes it in update mode.
+/// Returns a description of the difference.
+pub fn check_snapshot(path: &Path, actual: &str) -> Result<(), String> {
+    if update_mode() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, actual).unwrap();
+        return Ok(());
+    }
+    match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => Ok(()),
+        Ok(expected) => Err(format!("{} differs:\n{}", path.display(), diff(&expected, actual))),
+        Err(_) => Err(format!("{} is missing", path.display())),
+    }
+}
+
+/// A line diff of `expected` and `actual`, `-` for expected and `+` for
+/// actual lines, with a few lines of context around each change.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    // the longest common subsequence of every suffix
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    let mut out = String::new();
+    let mut last = None;
+    for (k, (sign, line)) in lines.iter().enumerate() {
+        let near = changed
+            .iter()
+            .any(|&c| k + CONTEXT >= c && k <= c + CONTEXT);
+        if !near {
+            continue;
+        }
+        if last.is_some_and(|last| k > last + 1) || (last.is_none() && k > 0) {
+            out.push_str("...\n");
+        }
+        out.push_str(&format!("{}{}\n", sign, line));
+        last = Some(k);
+    }
+    out
+}
@@ -0,0 +1,79 @@
+use figma2sprout::gen::{
+    component_generator::Generators, context::GenContext, node_util::convert_json_to_figma,
+    query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+};
+use serde_json::json;
+
+#[test]
+fn generates_the_shown_tags_only() {
+    let file = json!({
+        "name": "Tags",
+        "version": "1",
+        "components": {
+            "9:1": { "key": "download", "name": "icons/outline/download" }
+        },
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{
+                "id": "0:1",
+                "name": "Page 1",
+                "type": "CANVAS",
+                "children": [{
+                    "id": "5:1",
+                    "name": "TagGroup",
+                    "type": "INSTANCE",
+                    "componentProperties": {
+                        "Show second#2:1": { "type": "BOOLEAN", "value": false }
+                    },
+                    "children": [
+                        {
+                            "id": "5:2",
+                            "name": "Tag",
+                            "type": "INSTANCE",
+                            "componentProperties": {
+                                "Text#1:1": { "type": "TEXT", "value": "One" },
+                                "Icon instance#1:3": { "type": "INSTANCE_SWAP", "value": "9:1" },
+                                "Icon#1:2": { "type": "BOOLEAN", "value": true }
+                            }
+                        },
+                        {
+                            "id": "5:3",
+                            "name": "Tag",
+                            "type": "INSTANCE",
+                            "componentPropertyReferences": { "visible": "Show second#2:1" },
+                            "componentProperties": {
+                                "Text#1:1": { "type": "TEXT", "value": "Two" }
+                            }
+                        },
+                        {
+                            "id": "5:4",
+                            "name": "Alert.Inline",
+                            "type": "INSTANCE",
+                            "componentProperties": {
+                                "Severity": { "type": "VARIANT", "value": "Critical" }
+                            }
+                        }
+                    ]
+                }]
+            }]
+        }
+    });
+    let file = convert_json_to_figma(file.to_string()).unwrap();
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+
+    let group = find_by_id(&file.document, "5:1").unwrap();
+    let jsx = generators
+        .gen_component(group, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    assert_eq!(jsx, "<TagGroup>\n  <Tag label=\"One\" icon={<Download />} />\n</TagGroup>\n");
+    // the alert is not generated, so neither are its diagnostics
+    assert_eq!(ctx.take_diagnostics(), vec![]);
+}
@@ -0,0 +1,145 @@
+use figma2sprout::{
+    gen::{
+        node_util::convert_json_to_figma,
+        token_export::{
+            collect_style_tokens, to_css_variables, to_design_tokens_json, DesignToken, TokenValue,
+        },
+    },
+    schema::{Color, Effect},
+};
+use serde_json::{json, Value};
+
+fn token(name: &str, value: TokenValue) -> DesignToken {
+    DesignToken { name: name.to_string(), description: String::new(), value }
+}
+fn color(r: f64, g: f64, b: f64) -> TokenValue {
+    TokenValue::Color(Color { r, g, b, a: 1.0 })
+}
+fn shadows() -> Vec<Effect> {
+    serde_json::from_value(json!([
+        {
+            "type": "DROP_SHADOW",
+            "color": { "r": 0, "g": 0, "b": 0, "a": 1 },
+            "offset": { "x": 0, "y": 2 },
+            "radius": 4,
+            "spread": 1
+        },
+        {
+            "type": "INNER_SHADOW",
+            "color": { "r": 1, "g": 1, "b": 1, "a": 1 },
+            "offset": { "x": 1, "y": 1 },
+            "radius": 0
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn keeps_tokens_named_like_groups() {
+    let json = |tokens: &[DesignToken]| -> Value {
+        serde_json::from_str(&to_design_tokens_json(tokens)).unwrap()
+    };
+    let expected = json!({
+        "text": {
+            "DEFAULT": { "$type": "color", "$value": "#000000" },
+            "primary": { "$type": "color", "$value": "#ffffff" }
+        }
+    });
+    let tokens = [token("text", color(0.0, 0.0, 0.0)), token("text.primary", color(1.0, 1.0, 1.0))];
+    assert_eq!(json(&tokens), expected);
+    let tokens = [token("text.primary", color(1.0, 1.0, 1.0)), token("text", color(0.0, 0.0, 0.0))];
+    assert_eq!(json(&tokens), expected);
+
+    let tokens = [
+        token("size.m.dense", TokenValue::Dimension(4.0)),
+        token("size", TokenValue::Dimension(8.0)),
+        token("size.m", TokenValue::Dimension(6.0)),
+    ];
+    let dimension = |value: &str| json!({ "$type": "dimension", "$value": value });
+    assert_eq!(
+        json(&tokens),
+        json!({
+            "size": {
+                "DEFAULT": dimension("8px"),
+                "m": { "DEFAULT": dimension("6px"), "dense": dimension("4px") }
+            }
+        })
+    );
+}
+
+#[test]
+fn writes_shadows_and_dimensions() {
+    let tokens = [
+        token("elevation.strong", TokenValue::Shadow(shadows())),
+        token("spacing.m", TokenValue::Dimension(8.0)),
+    ];
+    let json: Value = serde_json::from_str(&to_design_tokens_json(&tokens)).unwrap();
+    assert_eq!(
+        json["elevation"]["strong"]["$value"],
+        json!([
+            {
+                "color": "#000000",
+                "offsetX": "0px",
+                "offsetY": "2px",
+                "blur": "4px",
+                "spread": "1px"
+            },
+            {
+                "color": "#ffffff",
+                "offsetX": "1px",
+                "offsetY": "1px",
+                "blur": "0px",
+                "spread": "0px",
+                "inset": true
+            }
+        ])
+    );
+    assert_eq!(json["spacing"]["m"], json!({ "$type": "dimension", "$value": "8px" }));
+
+    assert_eq!(
+        to_css_variables(&tokens),
+        ":root {\n  --elevation-strong: 0px 2px 4px 1px #000000, inset 1px 1px 0px 0px #ffffff;\n  --spacing-m: 8px;\n}\n"
+    );
+}
+
+#[test]
+fn escapes_css_strings() {
+    let tokens = [token("font.quote", TokenValue::String("Say \"hi\"\n\\ ok".to_string()))];
+    assert_eq!(
+        to_css_variables(&tokens),
+        ":root {\n  --font-quote: \"Say \\\"hi\\\"\\a \\\\ ok\";\n}\n"
+    );
+}
+
+#[test]
+fn reads_styles_from_the_nodes_using_them() {
+    let file = convert_json_to_figma(
+        json!({
+            "name": "Styles",
+            "version": "1",
+            "styles": {
+                "S:1": { "key": "s1", "name": "Light/Text/Primary", "styleType": "FILL" },
+                "S:2": { "key": "s2", "name": "Unused", "styleType": "EFFECT" }
+            },
+            "document": {
+                "id": "0:0",
+                "name": "Document",
+                "type": "DOCUMENT",
+                "children": [{
+                    "id": "1:1",
+                    "name": "Text",
+                    "type": "TEXT",
+                    "styles": { "fill": "S:1" },
+                    "fills": [{ "type": "SOLID", "color": { "r": 1, "g": 0, "b": 0, "a": 1 } }]
+                }]
+            }
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let (tokens, skipped) = collect_style_tokens(&file);
+    assert_eq!(skipped, ["Unused"]);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].name, "text.primary");
+    assert!(matches!(tokens[0].value, TokenValue::Color(Color { r: 1.0, .. })));
+}
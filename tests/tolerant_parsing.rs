@@ -0,0 +1,72 @@
+use figma2sprout::{
+    gen::{
+        node_util::convert_json_to_figma, style::node_sx, tokens::TokenResolver,
+        validation::validate_node_types,
+    },
+    schema::{BlendMode, EffectType, Node, NodeType, PaintType},
+};
+use serde_json::json;
+
+const FILE: &str = r#"{
+  "name": "FigJam board",
+  "version": "1",
+  "document": {
+    "id": "0:0",
+    "name": "Document",
+    "type": "DOCUMENT",
+    "children": [{
+      "id": "0:1",
+      "name": "Page 1",
+      "type": "CANVAS",
+      "children": [
+        { "id": "1:1", "name": "Sticky", "type": "STICKY", "authorVisible": true },
+        { "id": "1:2", "name": "Table", "type": "TABLE", "numRows": 2 },
+        { "id": "1:3", "name": "Gizmo", "type": "HOLOGRAM" }
+      ]
+    }]
+  }
+}"#;
+
+#[test]
+fn keeps_unknown_types_and_fields() {
+    let file = convert_json_to_figma(FILE.to_string()).unwrap();
+    let page = &file.document.children.as_ref().unwrap()[0];
+    let children = page.children.as_ref().unwrap();
+    assert_eq!(children[0].r#type, NodeType::Sticky);
+    assert_eq!(children[0].extras["authorVisible"], true);
+    assert_eq!(children[1].r#type, NodeType::Table);
+    assert_eq!(children[2].r#type, NodeType::Other("HOLOGRAM".to_string()));
+
+    let diagnostics = validate_node_types(&file);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].node_id, "1:3");
+}
+
+#[test]
+fn keeps_unknown_paints_and_effects() {
+    let node: Node = serde_json::from_value(json!({
+        "id": "1:1",
+        "name": "Frame",
+        "type": "FRAME",
+        "layoutMode": "SPIRAL",
+        "blendMode": "DISSOLVE",
+        "fills": [
+            { "type": "PATTERN" },
+            { "type": "SOLID", "color": { "r": 1, "g": 0, "b": 0, "a": 1 } }
+        ],
+        "effects": [{ "type": "TEXTURE", "radius": 4 }]
+    }))
+    .unwrap();
+    let fills = node.fills.as_ref().unwrap();
+    assert_eq!(fills[0].r#type, PaintType::Other);
+    assert_eq!(node.effects.as_ref().unwrap()[0].r#type, EffectType::Other);
+    assert_eq!(node.blend_mode, Some(BlendMode::Other));
+
+    // the unknown values are left out of the styling
+    let sx = node_sx(&node, &TokenResolver::new());
+    assert_eq!(sx.get("backgroundColor"), Some("'#ff0000'"));
+    assert_eq!(sx.get("background"), None);
+    assert_eq!(sx.get("filter"), None);
+    assert_eq!(sx.get("mixBlendMode"), None);
+    assert_eq!(sx.get("display"), None);
+}
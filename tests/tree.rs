@@ -0,0 +1,95 @@
+use figma2sprout::{
+    gen::{
+        component_generator::Generators,
+        context::GenContext,
+        diagnostic::Diagnostic,
+        node_util::{convert_json_to_figma, NodeIndex},
+        tokens::TokenResolver,
+        validation::PropertyDefinitions,
+    },
+    schema::NodeId,
+    ui::tree::{parse_file_to_tree, GenStatus},
+};
+use serde_json::json;
+use std::sync::Arc;
+
+#[test]
+fn badges_library_components_only() {
+    let file = json!({
+        "name": "Tree",
+        "version": "1",
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{
+                "id": "0:1",
+                "name": "Page 1",
+                "type": "CANVAS",
+                "children": [{
+                    "id": "1:1",
+                    "name": "Card",
+                    "type": "FRAME",
+                    "children": [
+                        { "id": "1:2", "name": "Title", "type": "TEXT", "characters": "Title" },
+                        {
+                            "id": "1:3",
+                            "name": "Badge",
+                            "type": "INSTANCE",
+                            "componentProperties": {
+                                "Text": { "type": "TEXT", "value": "3" }
+                            }
+                        }
+                    ]
+                }]
+            }]
+        }
+    });
+    let file = Arc::new(convert_json_to_figma(file.to_string()).unwrap());
+    let index = NodeIndex::new(file.clone());
+    let (definitions, tokens) = (PropertyDefinitions::new(), TokenResolver::new());
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let mut tree = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(parse_file_to_tree(file.clone()))
+        .unwrap();
+    tree[0].update_status(&index, &generators);
+    let mut computed = tree[0].clone();
+
+    let mut node = |id: &str| {
+        let path = index.path(&NodeId::from(id)).unwrap();
+        tree[0].find_child(&path).unwrap().clone()
+    };
+    // frames and texts generate a Box or a Typography, which is no news
+    assert_eq!(node("1:1").status, GenStatus::None);
+    assert_eq!(node("1:2").status, GenStatus::None);
+    let mut badge = node("1:3");
+    assert_eq!(
+        badge.status,
+        GenStatus::Generated { component: "Badge".to_string(), diagnostics: None }
+    );
+    let diagnostic = Diagnostic::warning(&file.document, "unknown property");
+    badge.set_diagnostics(vec![diagnostic.clone()]);
+    assert_eq!(
+        badge.status,
+        GenStatus::Generated {
+            component: "Badge".to_string(),
+            diagnostics: Some(vec![diagnostic])
+        }
+    );
+    let mut frame = node("1:1");
+    frame.set_diagnostics(vec![]);
+    assert_eq!(frame.status, GenStatus::None);
+
+    // the diagnostics are known for every badged node once the tree is built
+    computed
+        .update_diagnostics(&index, &GenContext::new(&file, &generators, &definitions, &tokens));
+    let path = index.path(&NodeId::from("1:3")).unwrap();
+    assert!(matches!(
+        computed.find_child(&path).unwrap().status,
+        GenStatus::Generated { diagnostics: Some(_), .. }
+    ));
+    let path = index.path(&NodeId::from("1:1")).unwrap();
+    assert_eq!(computed.find_child(&path).unwrap().status, GenStatus::None);
+}
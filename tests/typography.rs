@@ -0,0 +1,117 @@
+use figma2sprout::{
+    gen::{
+        component_generator::Generators, context::GenContext, node_util::convert_json_to_figma,
+        query::find_by_id, tokens::TokenResolver, validation::PropertyDefinitions,
+    },
+    schema::File,
+};
+use serde_json::{json, Value};
+
+fn file(texts: Value) -> File {
+    let file = json!({
+        "name": "Text",
+        "version": "1",
+        "document": {
+            "id": "0:0",
+            "name": "Document",
+            "type": "DOCUMENT",
+            "children": [{ "id": "0:1", "name": "Page 1", "type": "CANVAS", "children": texts }]
+        }
+    });
+    convert_json_to_figma(file.to_string()).unwrap()
+}
+fn render(file: &File, id: &str) -> String {
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(file, &generators, &definitions, &tokens);
+    let node = find_by_id(&file.document, id).unwrap();
+    generators
+        .gen_component(node, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render()
+}
+fn bold() -> Value {
+    json!({ "1": { "fontWeight": 700 } })
+}
+
+#[test]
+fn breaks_lines_ending_in_styled_runs() {
+    let file = file(json!([
+        {
+            "id": "1:1",
+            "name": "Text",
+            "type": "TEXT",
+            "characters": "Plain bold\nNext",
+            "characterStyleOverrides": [0, 0, 0, 0, 0, 0, 1, 1, 1, 1],
+            "styleOverrideTable": bold()
+        },
+        {
+            "id": "1:2",
+            "name": "Text",
+            "type": "TEXT",
+            "characters": "bold\n\nplain",
+            "characterStyleOverrides": [1, 1, 1, 1],
+            "styleOverrideTable": bold()
+        }
+    ]));
+    assert_eq!(
+        render(&file, "1:1"),
+        "<Typography>
+  {\"Plain \"}
+  <Box component=\"span\" sx={{ fontWeight: 700 }}>
+    bold
+  </Box>
+  <br />
+  Next
+</Typography>
+"
+    );
+    // an empty line is a break of its own
+    assert_eq!(
+        render(&file, "1:2"),
+        "<Typography>
+  <Box component=\"span\" sx={{ fontWeight: 700 }}>
+    bold
+  </Box>
+  <br />
+  <br />
+  plain
+</Typography>
+"
+    );
+}
+
+#[test]
+fn does_not_break_around_lists() {
+    let file = file(json!([{
+        "id": "1:1",
+        "name": "Text",
+        "type": "TEXT",
+        "characters": "Steps\none\ntwo\nDone",
+        "characterStyleOverrides": [1, 1, 1, 1, 1],
+        "styleOverrideTable": bold(),
+        "lineTypes": ["NONE", "ORDERED", "ORDERED", "NONE"],
+        "lineIndentations": [0, 1, 1, 0]
+    }]));
+    assert_eq!(
+        render(&file, "1:1"),
+        "<Typography component=\"div\">
+  <Box component=\"span\" sx={{ fontWeight: 700 }}>
+    Steps
+  </Box>
+  <ol>
+    <li>
+      one
+    </li>
+    <li>
+      two
+    </li>
+  </ol>
+  Done
+</Typography>
+"
+    );
+}
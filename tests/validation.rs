@@ -0,0 +1,222 @@
+use figma2sprout::{
+    gen::{
+        component_generator::Generators,
+        context::GenContext,
+        diagnostic::Severity,
+        node_util::convert_json_to_figma,
+        query::find_by_id,
+        tokens::TokenResolver,
+        validation::{validate_file, validate_instance, PropertyDefinitions},
+    },
+    schema::{File, Node},
+};
+
+const FILE: &str = r#"{
+  "name": "Alerts",
+  "version": "1",
+  "components": {
+    "1:10": { "key": "alert-info", "name": "Severity=Info", "componentSetId": "1:1" }
+  },
+  "componentSets": {
+    "1:1": { "key": "alert-set", "name": "Alert.Inline" }
+  },
+  "document": {
+    "id": "0:0",
+    "name": "Document",
+    "type": "DOCUMENT",
+    "children": [{
+      "id": "0:1",
+      "name": "Page 1",
+      "type": "CANVAS",
+      "children": [
+        {
+          "id": "1:1",
+          "name": "Alert.Inline",
+          "type": "COMPONENT_SET",
+          "componentPropertyDefinitions": {
+            "Severity": { "type": "VARIANT", "defaultValue": "Info", "variantOptions": ["Info", "Error"] },
+            "Title#1:2": { "type": "BOOLEAN", "defaultValue": true },
+            "Title text#1:3": { "type": "TEXT", "defaultValue": "Title" }
+          },
+          "children": [{ "id": "1:10", "name": "Severity=Info", "type": "COMPONENT" }]
+        },
+        {
+          "id": "2:1",
+          "name": "Alert.Inline",
+          "type": "INSTANCE",
+          "componentId": "1:10",
+          "componentProperties": {
+            "Severity": { "type": "VARIANT", "value": "Info" },
+            "Title#1:2": { "type": "BOOLEAN", "value": false },
+            "Title text#1:3": { "type": "TEXT", "value": "Title" }
+          }
+        },
+        {
+          "id": "2:2",
+          "name": "Alert.Inline",
+          "type": "INSTANCE",
+          "componentId": "1:10",
+          "componentProperties": {
+            "Severity": { "type": "VARIANT", "value": "Critical" },
+            "Title#1:2": { "type": "TEXT", "value": "yes" },
+            "Extra#9:9": { "type": "BOOLEAN", "value": true }
+          }
+        },
+        {
+          "id": "2:3",
+          "name": "Alert.Inline",
+          "type": "INSTANCE",
+          "componentId": "1:10",
+          "componentProperties": {
+            "Severity": { "type": "VARIANT", "value": "Error" },
+            "Title#1:2": { "type": "BOOLEAN", "value": true },
+            "Title text#1:3": { "type": "TEXT", "value": "Heads up" }
+          }
+        },
+        { "id": "3:1", "name": "Detached", "type": "INSTANCE", "componentId": "8:8" }
+      ]
+    }]
+  }
+}"#;
+
+fn file() -> File {
+    convert_json_to_figma(FILE.to_string()).unwrap()
+}
+
+#[test]
+fn collects_definitions_by_component_set_key() {
+    let file = file();
+    let definitions = PropertyDefinitions::from_file(&file);
+    assert!(!definitions.is_empty());
+
+    let instance = find_by_id(&file.document, "2:1").unwrap();
+    let library = definitions.for_instance(&file, instance).unwrap();
+    assert_eq!(library.len(), 3);
+    assert!(definitions.find(&file, instance, "Title").is_some());
+    assert!(definitions.find(&file, instance, "Tit").is_none());
+
+    let detached = find_by_id(&file.document, "3:1").unwrap();
+    assert!(definitions.for_instance(&file, detached).is_none());
+}
+
+#[test]
+fn validates_instances_against_definitions() {
+    let file = file();
+    let definitions = PropertyDefinitions::from_file(&file);
+
+    let valid = find_by_id(&file.document, "2:1").unwrap();
+    assert_eq!(validate_instance(&definitions, &file, valid), vec![]);
+
+    let invalid = find_by_id(&file.document, "2:2").unwrap();
+    let messages: Vec<(Severity, String)> = validate_instance(&definitions, &file, invalid)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.severity, diagnostic.message))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            (
+                Severity::Warning,
+                "property `Extra#9:9` is not defined by the library component".to_string()
+            ),
+            (
+                Severity::Error,
+                "variant `Severity` has value `Critical`, expected one of: Info, Error".to_string()
+            ),
+            (
+                Severity::Error,
+                "property `Title#1:2` is Text but the library defines it as Boolean".to_string()
+            ),
+            (
+                Severity::Warning,
+                "property `Title text#1:3` of the library component is missing".to_string()
+            ),
+        ]
+    );
+
+    let detached = find_by_id(&file.document, "3:1").unwrap();
+    assert_eq!(validate_instance(&definitions, &file, detached), vec![]);
+
+    let diagnostics = validate_file(&definitions, &file);
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.node_id == "2:2"));
+    assert_eq!(diagnostics.len(), 4);
+}
+
+#[test]
+fn compares_properties_with_library_defaults() {
+    let file = file();
+    let definitions = PropertyDefinitions::from_file(&file);
+    let tokens = TokenResolver::new();
+    let generators = Generators::new();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+
+    let defaults = find_by_id(&file.document, "2:1").unwrap();
+    assert!(ctx.is_default(defaults, "Severity"));
+    assert!(ctx.is_default(defaults, "Title text"));
+    assert!(!ctx.is_default(defaults, "Title"));
+    // a property without a definition is never the default
+    assert!(!ctx.is_default(defaults, "Extra"));
+
+    let changed = find_by_id(&file.document, "2:3").unwrap();
+    assert!(!ctx.is_default(changed, "Severity"));
+    assert!(ctx.is_default(changed, "Title"));
+}
+
+#[test]
+fn reads_title_apart_from_title_text() {
+    let file = file();
+    let definitions = PropertyDefinitions::from_file(&file);
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+
+    let with_title = find_by_id(&file.document, "2:3").unwrap();
+    let jsx = generators
+        .gen_component(with_title, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    assert_eq!(
+        jsx,
+        "<Alert severity=\"error\">\n  <AlertTitle>\n    Heads up\n  </AlertTitle>\n</Alert>\n"
+    );
+
+    let without_title = find_by_id(&file.document, "2:1").unwrap();
+    let jsx = generators
+        .gen_component(without_title, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    assert!(!jsx.contains("AlertTitle"), "{}", jsx);
+    assert!(!jsx.contains("severity"), "{}", jsx);
+    assert_eq!(ctx.take_diagnostics(), vec![]);
+}
+
+#[test]
+fn matches_properties_by_their_whole_name() {
+    let badge: Node = serde_json::from_value(serde_json::json!({
+        "id": "3:1",
+        "name": "Badge",
+        "type": "INSTANCE",
+        "componentProperties": {
+            "Text color#3:2": { "type": "TEXT", "value": "red" }
+        }
+    }))
+    .unwrap();
+    let file = file();
+    let definitions = PropertyDefinitions::new();
+    let tokens = TokenResolver::new();
+    let mut generators = Generators::new();
+    generators.register_generators();
+    let ctx = GenContext::new(&file, &generators, &definitions, &tokens);
+    let jsx = generators
+        .gen_component(&badge, &ctx)
+        .unwrap()
+        .to_jsx()
+        .render();
+    // `Text` is not `Text color`
+    assert!(!jsx.contains("red"), "{}", jsx);
+}
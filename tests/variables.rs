@@ -0,0 +1,172 @@
+use figma2sprout::{
+    gen::{
+        node_util::convert_json_to_figma,
+        tokens::TokenResolver,
+        variables::{convert_json_to_local_variables, variable_key, VariableResolver},
+    },
+    schema::{Color, VariableValue},
+};
+
+const VARIABLES: &str = r#"{
+  "status": 200,
+  "error": false,
+  "meta": {
+    "variableCollections": {
+      "VC:1": {
+        "id": "VC:1", "name": "Primitives", "key": "c1", "defaultModeId": "1:0",
+        "modes": [{ "modeId": "1:0", "name": "Light" }, { "modeId": "1:1", "name": "Dark" }]
+      },
+      "VC:2": {
+        "id": "VC:2", "name": "Semantic", "key": "c2", "defaultModeId": "2:0",
+        "modes": [{ "modeId": "2:0", "name": "Light" }, { "modeId": "2:1", "name": "Dark" }]
+      },
+      "VC:3": {
+        "id": "VC:3", "name": "Brand", "key": "c3", "defaultModeId": "3:0",
+        "modes": [{ "modeId": "3:0", "name": "Default" }]
+      }
+    },
+    "variables": {
+      "V:1": {
+        "id": "V:1", "name": "blue/500", "key": "k1", "variableCollectionId": "VC:1",
+        "resolvedType": "COLOR",
+        "valuesByMode": {
+          "1:0": { "r": 0, "g": 0, "b": 1, "a": 1 },
+          "1:1": { "r": 0, "g": 0, "b": 0.5, "a": 1 }
+        }
+      },
+      "V:2": {
+        "id": "V:2", "name": "Light/Text/Primary", "key": "k2", "variableCollectionId": "VC:2",
+        "resolvedType": "COLOR",
+        "valuesByMode": {
+          "2:0": { "type": "VARIABLE_ALIAS", "id": "V:3" },
+          "2:1": { "type": "VARIABLE_ALIAS", "id": "V:3" }
+        }
+      },
+      "V:3": {
+        "id": "V:3", "name": "color/accent", "key": "k3", "variableCollectionId": "VC:2",
+        "resolvedType": "COLOR",
+        "valuesByMode": {
+          "2:0": { "type": "VARIABLE_ALIAS", "id": "V:1" },
+          "2:1": { "type": "VARIABLE_ALIAS", "id": "V:1" }
+        }
+      },
+      "V:4": {
+        "id": "V:4", "name": "loop/a", "key": "k4", "variableCollectionId": "VC:2",
+        "resolvedType": "FLOAT",
+        "valuesByMode": { "2:0": { "type": "VARIABLE_ALIAS", "id": "V:5" } }
+      },
+      "V:5": {
+        "id": "V:5", "name": "loop/b", "key": "k5", "variableCollectionId": "VC:2",
+        "resolvedType": "FLOAT",
+        "valuesByMode": { "2:0": { "type": "VARIABLE_ALIAS", "id": "V:4" } }
+      },
+      "V:6": {
+        "id": "V:6", "name": "brand/primary", "key": "k6", "variableCollectionId": "VC:3",
+        "resolvedType": "COLOR",
+        "valuesByMode": { "3:0": { "type": "VARIABLE_ALIAS", "id": "V:1" } }
+      },
+      "V:7": {
+        "id": "V:7", "name": "broken", "key": "k7", "variableCollectionId": "VC:2",
+        "resolvedType": "FLOAT",
+        "valuesByMode": { "2:0": { "type": "VARIABLE_ALIAS", "id": "V:99" } }
+      }
+    }
+  }
+}"#;
+
+const FILE: &str = r#"{
+  "name": "Tokens",
+  "version": "1",
+  "document": {
+    "id": "0:0",
+    "name": "Document",
+    "type": "DOCUMENT",
+    "children": [{
+      "id": "0:1",
+      "name": "Page 1",
+      "type": "CANVAS",
+      "children": [
+        {
+          "id": "1:1",
+          "name": "Card",
+          "type": "FRAME",
+          "boundVariables": {
+            "fills": [{ "type": "VARIABLE_ALIAS", "id": "VariableID:k2/12:34" }],
+            "size": {
+              "y": { "type": "VARIABLE_ALIAS", "id": "V:3" },
+              "x": { "type": "VARIABLE_ALIAS", "id": "V:1" }
+            },
+            "paddingLeft": { "type": "VARIABLE_ALIAS", "id": "V:99" }
+          }
+        }
+      ]
+    }]
+  }
+}"#;
+
+fn resolver() -> VariableResolver {
+    VariableResolver::new(convert_json_to_local_variables(VARIABLES).unwrap())
+}
+fn color(value: Option<&VariableValue>) -> Option<&Color> {
+    match value? {
+        VariableValue::Color(color) => Some(color),
+        _ => None,
+    }
+}
+
+#[test]
+fn finds_variables_by_id_and_library_key() {
+    let variables = resolver();
+    assert_eq!(variable_key("VariableID:k1/2612:91"), Some("k1"));
+    assert_eq!(variable_key("V:1"), None);
+    assert_eq!(variables.variable("V:1").unwrap().name, "blue/500");
+    assert_eq!(variables.variable("VariableID:k1/2612:91").unwrap().id, "V:1");
+    assert!(variables.variable("VariableID:unknown/1:2").is_none());
+}
+
+#[test]
+fn follows_alias_chains_across_collections_per_mode() {
+    let variables = resolver();
+    let dark = variables.mode_id("V:2", "Dark").unwrap();
+    assert_eq!(dark, "2:1");
+
+    // V:2 -> V:3 -> V:1, from the semantic into the primitive collection
+    let light = color(variables.resolve("V:2", None)).unwrap();
+    assert_eq!((light.b, light.a), (1.0, 1.0));
+    let dark = color(variables.resolve("V:2", Some(dark))).unwrap();
+    assert_eq!(dark.b, 0.5);
+    // a mode of another collection is ignored
+    assert_eq!(color(variables.resolve("V:2", Some("1:1"))).unwrap().b, 1.0);
+    // the brand collection has no `Default` mode in the primitives
+    assert_eq!(color(variables.resolve("V:6", None)).unwrap().b, 1.0);
+}
+
+#[test]
+fn stops_at_cycles_and_missing_variables() {
+    let variables = resolver();
+    assert_eq!(variables.resolve("V:4", None), None);
+    assert_eq!(variables.resolve("V:5", None), None);
+    assert_eq!(variables.resolve("V:7", None), None);
+    assert_eq!(variables.resolve("V:99", None), None);
+}
+
+#[test]
+fn maps_bound_variables_to_tokens() {
+    let file = convert_json_to_figma(FILE.to_string()).unwrap();
+    let mut tokens = TokenResolver::from_file(&file);
+    tokens.add_local_variables(&resolver());
+    let card = &file.document.children.as_ref().unwrap()[0]
+        .children
+        .as_ref()
+        .unwrap()[0];
+
+    assert_eq!(tokens.fill_token(card), Some("text.primary"));
+    // of a map, the sub-field first by name
+    assert_eq!(tokens.bound_token(card, "size"), Some("blue.500"));
+    let size = &card.bound_variables.as_ref().unwrap()["size"];
+    assert_eq!(size.field("y").unwrap().id, "V:3");
+    // a variable that is neither local nor published stays unresolved
+    assert_eq!(tokens.bound_token(card, "paddingLeft"), None);
+    assert_eq!(tokens.bound_token(card, "paddingRight"), None);
+    assert_eq!(tokens.variable_token("VariableID:unknown/1:2"), None);
+}